@@ -0,0 +1,135 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Procedural macros for `sage`. These are re-exported by `sage` itself, so
+//! depend on `sage` rather than on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{meta::ParseNestedMeta, parse_macro_input, DeriveInput, LitStr};
+
+/// Implements `sage::vocab::Vocabulary` for a (zero-sized) struct.
+///
+/// See `sage::vocab::sage_vocab` for documentation.
+#[proc_macro_attribute]
+pub fn sage_vocab(args: TokenStream, item: TokenStream) -> TokenStream {
+  let mut attrs = VocabAttrs::default();
+  let parser = syn::meta::parser(|meta| attrs.parse(meta));
+  parse_macro_input!(args with parser);
+
+  let input = parse_macro_input!(item as DeriveInput);
+  match attrs.expand(&input) {
+    Ok(tokens) => tokens.into(),
+    Err(err) => err.into_compile_error().into(),
+  }
+}
+
+#[derive(Default)]
+struct VocabAttrs {
+  prefix: Option<LitStr>,
+  full: Option<LitStr>,
+  terms: Vec<(syn::Ident, LitStr)>,
+}
+
+impl VocabAttrs {
+  fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+    if meta.path.is_ident("prefix") {
+      let prefix: LitStr = meta.value()?.parse()?;
+      if !prefix.value().ends_with(':') {
+        return Err(syn::Error::new(
+          prefix.span(),
+          "vocabulary prefix must end with `:`",
+        ));
+      }
+      self.prefix = Some(prefix);
+    } else if meta.path.is_ident("full") {
+      let full: LitStr = meta.value()?.parse()?;
+      if !full.value().ends_with(['/', '#']) {
+        return Err(syn::Error::new(
+          full.span(),
+          "vocabulary IRI must end with `/` or `#`",
+        ));
+      }
+      self.full = Some(full);
+    } else if meta.path.is_ident("terms") {
+      meta.parse_nested_meta(|term| {
+        let name = term.path.require_ident()?.clone();
+        let local: LitStr = term.value()?.parse()?;
+        self.terms.push((name, local));
+        Ok(())
+      })?;
+    } else {
+      return Err(meta.error("expected `prefix`, `full` or `terms`"));
+    }
+    Ok(())
+  }
+
+  fn expand(
+    &self,
+    input: &DeriveInput,
+  ) -> syn::Result<proc_macro2::TokenStream> {
+    let missing = |name: &str| {
+      syn::Error::new(
+        Span::call_site(),
+        format!("missing `{} = \"...\"` in #[sage_vocab]", name),
+      )
+    };
+    let prefix = self.prefix.as_ref().ok_or_else(|| missing("prefix"))?;
+    let full = self.full.as_ref().ok_or_else(|| missing("full"))?;
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+      input.generics.split_for_impl();
+
+    let terms = self.terms.iter().map(|(name, local)| {
+      let iri = format!("{}{}", full.value(), local.value());
+      let doc = format!("`{}`", iri);
+      quote! {
+        #[doc = #doc]
+        pub const #name: &'static str = #iri;
+      }
+    });
+    let terms = if self.terms.is_empty() {
+      quote!()
+    } else {
+      quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+          #(#terms)*
+        }
+      }
+    };
+
+    Ok(quote! {
+      #input
+
+      impl #impl_generics ::sage::vocab::Vocabulary for #ident #ty_generics
+        #where_clause
+      {
+        type Prefix = ::sage::dtype::IRI;
+        type Full = ::sage::dtype::IRI;
+
+        fn prefix() -> Self::Prefix {
+          ::sage::dtype::IRI::from(#prefix)
+        }
+
+        fn full() -> Self::Full {
+          ::sage::dtype::IRI::from(#full)
+        }
+      }
+
+      #terms
+    })
+  }
+}
@@ -14,6 +14,7 @@
 
 mod de;
 mod iter;
+#[cfg(feature = "raw_dtype")]
 mod raw;
 mod read;
 mod ser;
@@ -33,6 +34,9 @@ pub use ser::{
 };
 
 // Raw dtype.
+#[cfg(feature = "raw_dtype")]
+pub(crate) use raw::BoxedFromString;
+#[cfg(feature = "raw_dtype")]
 pub use raw::{
   to_raw_dtype, BorrowedRawDeserializer, OwnedRawDeserializer, RawDType, TOKEN,
 };
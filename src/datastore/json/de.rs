@@ -1306,7 +1306,7 @@ impl<'de, R: Read<'de>> Deserializer<R> {
     Ok(())
   }
 
-  #[cfg(feature = "raw_value")]
+  #[cfg(feature = "raw_dtype")]
   fn deserialize_raw_value<V>(&mut self, visitor: V) -> Result<V::Value>
   where
     V: de::Visitor<'de>,
@@ -1820,9 +1820,9 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
   where
     V: de::Visitor<'de>,
   {
-    #[cfg(feature = "raw_value")]
+    #[cfg(feature = "raw_dtype")]
     {
-      if name == crate::raw::TOKEN {
+      if name == crate::json::TOKEN {
         return self.deserialize_raw_value(visitor);
       }
     }
@@ -2609,7 +2609,7 @@ where
 ///
 /// Reading from a persistent socket connection.
 ///
-/// ```rust,ignore
+/// ```rust
 /// use serde_derive::Deserialize;
 ///
 /// use std::error::Error;
@@ -2623,7 +2623,7 @@ where
 ///
 /// fn read_user_from_stream(tcp_stream: TcpStream) -> Result<User, Box<dyn Error>> {
 ///   let mut de = sage::json::Deserializer::from_reader(tcp_stream);
-///   let u = User::deserialize(&mut de)?;
+///   let u: User = serde::Deserialize::deserialize(&mut de)?;
 ///
 ///   Ok(u)
 /// }
@@ -57,7 +57,7 @@ pub const TOKEN: &str = "$sage::raw::RawDType";
 ///
 /// # Example
 ///
-/// ```rust,ignore
+/// ```rust
 /// use serde_derive::{Deserialize, Serialize};
 /// use sage::{Result, json::RawDType};
 ///
@@ -209,7 +209,7 @@ impl RawDType {
   ///
   /// # Example
   ///
-  /// ```rust,ignore
+  /// ```rust
   /// use serde_derive::Deserialize;
   /// use sage::{Result, json::RawDType};
   ///
@@ -255,7 +255,7 @@ impl RawDType {
 ///
 /// # Example
 ///
-/// ```rust,ignore
+/// ```rust
 /// # use serde_derive::Serialize;
 /// #
 /// // Upstream crate
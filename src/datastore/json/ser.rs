@@ -454,7 +454,9 @@ where
   ) -> Result<Self::SerializeStruct> {
     match name {
       #[cfg(feature = "arbitrary_precision")]
-      crate::number::TOKEN => Ok(Compound::Number { ser: self }),
+      crate::number::TOKEN | crate::number::SERDE_JSON_TOKEN => {
+        Ok(Compound::Number { ser: self })
+      }
       #[cfg(feature = "raw_dtype")]
       crate::json::raw::TOKEN => Ok(Compound::RawDType { ser: self }),
       _ => self.serialize_map(Some(len)),
@@ -820,7 +822,8 @@ where
       }
       #[cfg(feature = "arbitrary_precision")]
       Compound::Number { ref mut ser, .. } => {
-        if key == crate::number::TOKEN {
+        if key == crate::number::TOKEN || key == crate::number::SERDE_JSON_TOKEN
+        {
           tri!(value.serialize(NumberStrEmitter(&mut *ser)));
           Ok(())
         } else {
@@ -830,7 +833,7 @@ where
       #[cfg(feature = "raw_dtype")]
       Compound::RawDType { ref mut ser, .. } => {
         if key == crate::json::raw::TOKEN {
-          tri!(value.serialize(RawDTypeStrEmitter(&mut *ser)));
+          tri!(value.serialize(RawDTypeStrEmitter(*ser)));
           Ok(())
         } else {
           Err(invalid_raw_dtype())
@@ -19,19 +19,35 @@
 //!
 //! [Strings]: https://doc.rust-lang.org/stable/alloc/string/struct.String.html
 
-use std::fmt;
+use std::{fmt, mem};
 
 use serde::{de::DeserializeOwned, ser::Serialize};
 
-use crate::Result;
+use crate::{vocab::SchemaVocab, Result};
 
+pub mod builder;
+pub mod compress;
 pub mod datetime;
+#[cfg(feature = "arbitrary_precision")]
+pub mod decimal;
 pub mod map;
 pub mod number;
 mod ops;
+pub mod pointer;
+mod pretty;
+mod walk;
 
 // Re-export public members.
-pub use {datetime::DateTime, map::Map, number::Number, ops::*};
+pub use {
+  builder::{ArrayBuilder, ObjectBuilder},
+  compress::CompressedDType,
+  datetime::DateTime,
+  map::Map,
+  number::Number,
+  ops::*,
+  pointer::PointerEntry,
+  pretty::PrettyPrintConfig,
+};
 
 /// `IRI` stands for International Resource Identifer. (ex: <name>).
 pub type IRI = String;
@@ -376,6 +392,43 @@ impl DType {
     matches!(*self, DType::Number(_))
   }
 
+  /// If the `DType` is a Number, returns the associated `Number`. Returns
+  /// `None` otherwise.
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let obj = json!({ "a": 256.0, "b": "256" });
+  ///
+  /// assert!(obj["a"].as_number().unwrap().is_f64());
+  /// assert_eq!(obj["b"].as_number(), None);
+  /// ```
+  pub fn as_number(&self) -> Option<&Number> {
+    match *self {
+      DType::Number(ref n) => Some(n),
+      _ => None,
+    }
+  }
+
+  /// If the `DType` is a Number, returns the associated mutable `Number`.
+  /// Returns `None` otherwise.
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// use sage::dtype::Number;
+  ///
+  /// let mut obj = json!({ "a": 65 });
+  ///
+  /// *obj["a"].as_number_mut().unwrap() = Number::from(66);
+  /// assert_eq!(obj, json!({ "a": 66 }));
+  /// ```
+  pub fn as_number_mut(&mut self) -> Option<&mut Number> {
+    match *self {
+      DType::Number(ref mut n) => Some(n),
+      _ => None,
+    }
+  }
+
   /// Returns true if the `DType` is an integer between `i64::MIN` and
   /// `i64::MAX`.
   ///
@@ -396,7 +449,7 @@ impl DType {
   /// assert!(!obj["c"].is_i64());
   /// ```
   pub fn is_i64(&self) -> bool {
-    matches!(*self, DType::Number(ref n) if n.is_i64())
+    self.as_number().is_some_and(Number::is_i64)
   }
 
   /// Returns true if the `DType` is an integer between zero and `u64::MAX`.
@@ -417,7 +470,7 @@ impl DType {
   /// assert!(!obj["c"].is_u64());
   /// ```
   pub fn is_u64(&self) -> bool {
-    matches!(*self, DType::Number(ref n) if n.is_u64())
+    self.as_number().is_some_and(Number::is_u64)
   }
 
   /// Returns true if `DType` is a number that can be represented by `f64`.
@@ -439,7 +492,7 @@ impl DType {
   /// assert!(!obj["c"].is_f64());
   /// ```
   pub fn is_f64(&self) -> bool {
-    matches!(*self, DType::Number(ref n) if n.is_f64())
+    self.as_number().is_some_and(Number::is_f64)
   }
 
   /// If the `DType` is an integer, represent it as `i64` if possible. Returns
@@ -456,10 +509,7 @@ impl DType {
   /// assert_eq!(obj["c"].as_i64(), None);
   /// ```
   pub fn as_i64(&self) -> Option<i64> {
-    match *self {
-      DType::Number(ref n) => n.as_i64(),
-      _ => None,
-    }
+    self.as_number().and_then(Number::as_i64)
   }
 
   /// If the `DType` is an integer, represent it as `u64` if possible. Returns
@@ -475,10 +525,7 @@ impl DType {
   /// assert_eq!(obj["c"].as_u64(), None);
   /// ```
   pub fn as_u64(&self) -> Option<u64> {
-    match *self {
-      DType::Number(ref n) => n.as_u64(),
-      _ => None,
-    }
+    self.as_number().and_then(Number::as_u64)
   }
 
   /// If the `DType` is a number, represent it as `f64` if possible. Returns
@@ -494,10 +541,7 @@ impl DType {
   /// assert_eq!(obj["c"].as_f64(), Some(-65.0));
   /// ```
   pub fn as_f64(&self) -> Option<f64> {
-    match *self {
-      DType::Number(ref n) => n.as_f64(),
-      _ => None,
-    }
+    self.as_number().and_then(Number::as_f64)
   }
 
   /// Returns true if `DType` is a `Boolean`. Returns false otherwise.
@@ -573,6 +617,603 @@ impl DType {
     }
   }
 
+  /// If the `DType` is a String, returns the inner `String` without cloning
+  /// it. Returns `None` otherwise.
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let s: String = json!("Avatar").into_string().unwrap();
+  /// assert_eq!(s, "Avatar");
+  ///
+  /// assert_eq!(json!(2009).into_string(), None);
+  /// ```
+  pub fn into_string(self) -> Option<String> {
+    match self {
+      DType::String(s) => Some(s),
+      _ => None,
+    }
+  }
+
+  /// If the `DType` is an Array, returns the inner `Vec` without cloning it.
+  /// Returns `None` otherwise.
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let v = json!([1, "two"]).into_array().unwrap();
+  /// assert_eq!(v, vec![json!(1), json!("two")]);
+  ///
+  /// assert_eq!(json!({}).into_array(), None);
+  /// ```
+  pub fn into_array(self) -> Option<Vec<DType>> {
+    match self {
+      DType::Array(v) => Some(v),
+      _ => None,
+    }
+  }
+
+  /// If the `DType` is an Object, returns the inner `Map` without cloning it.
+  /// Returns `None` otherwise.
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let m = json!({ "a": 1 }).into_object().unwrap();
+  /// assert_eq!(m["a"], json!(1));
+  ///
+  /// assert_eq!(json!([]).into_object(), None);
+  /// ```
+  pub fn into_object(self) -> Option<Map<String, DType>> {
+    match self {
+      DType::Object(m) => Some(m),
+      _ => None,
+    }
+  }
+
+  /// If the `DType` is a Number, returns the inner `Number`. Returns `None`
+  /// otherwise.
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let n = json!(256.0).into_number().unwrap();
+  /// assert!(n.is_f64());
+  ///
+  /// assert_eq!(json!("256").into_number(), None);
+  /// ```
+  pub fn into_number(self) -> Option<Number> {
+    match self {
+      DType::Number(n) => Some(n),
+      _ => None,
+    }
+  }
+
+  /// If the `DType` is a `Boolean`, returns the associated bool. Returns
+  /// `None` otherwise.
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// assert_eq!(json!(true).into_bool(), Some(true));
+  /// assert_eq!(json!("true").into_bool(), None);
+  /// ```
+  pub fn into_bool(self) -> Option<bool> {
+    self.as_bool()
+  }
+
+  /// Counts the values matching `pred` in a depth-first traversal of the
+  /// `DType`. Every value is visited: the `DType` itself, arrays & objects,
+  /// and everything nested in them.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let data = json!({ "a": [1, -2, { "b": -3 }], "c": "-4" });
+  /// assert_eq!(data.count_matching(|v| v.as_i64().is_some_and(|n| n < 0)), 2);
+  /// ```
+  pub fn count_matching<F>(&self, pred: F) -> usize
+  where
+    F: Fn(&DType) -> bool,
+  {
+    walk::DepthFirst::new(self).filter(|v| pred(v)).count()
+  }
+
+  /// Returns true if any value in the `DType` (itself included) matches
+  /// `pred`. Stops at the first match.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let data = json!({ "name": "Sage", "tags": [null] });
+  /// assert!(data.any_matching(|v| v.is_null()));
+  /// ```
+  pub fn any_matching<F>(&self, pred: F) -> bool
+  where
+    F: Fn(&DType) -> bool,
+  {
+    walk::DepthFirst::new(self).any(pred)
+  }
+
+  /// Returns true if every value in the `DType` (itself included) matches
+  /// `pred`. Stops at the first mismatch.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let data = json!([[1, 2], [3]]);
+  /// assert!(data.all_matching(|v| v.is_array() || v.is_number()));
+  /// assert!(!data.all_matching(|v| v.is_number()));
+  /// ```
+  pub fn all_matching<F>(&self, pred: F) -> bool
+  where
+    F: Fn(&DType) -> bool,
+  {
+    !self.any_matching(|v| !pred(v))
+  }
+
+  /// Counts the `Null` values in the `DType`, itself included.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let data = json!({ "a": null, "b": [null, "x", 1, 2.5] });
+  /// assert_eq!(data.count_nulls(), 2);
+  /// assert_eq!(data.count_strings(), 1);
+  /// assert_eq!(data.count_numbers(), 2);
+  /// ```
+  pub fn count_nulls(&self) -> usize {
+    self.count_matching(DType::is_null)
+  }
+
+  /// Counts the `String` values in the `DType`, itself included.
+  pub fn count_strings(&self) -> usize {
+    self.count_matching(DType::is_string)
+  }
+
+  /// Counts the `Number` values in the `DType`, itself included.
+  pub fn count_numbers(&self) -> usize {
+    self.count_matching(DType::is_number)
+  }
+
+  /// Calls `f` with every object in the `DType`, itself included. Values are
+  /// visited depth-first, a parent before its children.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let movie = json!({
+  ///   "title": "Avatar",
+  ///   "director": {"name": "James Cameron"},
+  ///   "cast": [{"name": "Sam Worthington"}, {"name": "Zoe Saldana"}],
+  /// });
+  ///
+  /// let mut names = Vec::new();
+  /// movie.visit_objects(|object| {
+  ///   if let Some(name) = object.get("name").and_then(|n| n.as_str()) {
+  ///     names.push(name.to_string());
+  ///   }
+  /// });
+  /// names.sort();
+  /// assert_eq!(names, ["James Cameron", "Sam Worthington", "Zoe Saldana"]);
+  /// ```
+  pub fn visit_objects<F>(&self, mut f: F)
+  where
+    F: FnMut(&Map<String, DType>),
+  {
+    for value in walk::DepthFirst::new(self) {
+      if let DType::Object(m) = value {
+        f(m)
+      }
+    }
+  }
+
+  /// Calls `f` with every array in the `DType`, itself included. Values are
+  /// visited depth-first, a parent before its children.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let matrix = json!([[1, 2], [3, 4, 5]]);
+  ///
+  /// let mut lengths = Vec::new();
+  /// matrix.visit_arrays(|array| lengths.push(array.len()));
+  /// assert_eq!(lengths, [2, 2, 3]);
+  /// ```
+  pub fn visit_arrays<F>(&self, mut f: F)
+  where
+    F: FnMut(&[DType]),
+  {
+    for value in walk::DepthFirst::new(self) {
+      if let DType::Array(v) = value {
+        f(v)
+      }
+    }
+  }
+
+  /// Calls `f` with every string in the `DType`, itself included. Values are
+  /// visited depth-first; object keys are not visited.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let data = json!({"tags": ["rust", "graph"], "stars": 42});
+  ///
+  /// let mut total = 0;
+  /// data.visit_strings(|s| total += s.len());
+  /// assert_eq!(total, 9);
+  /// ```
+  pub fn visit_strings<F>(&self, mut f: F)
+  where
+    F: FnMut(&str),
+  {
+    for value in walk::DepthFirst::new(self) {
+      if let DType::String(s) = value {
+        f(s)
+      }
+    }
+  }
+
+  /// Returns a copy of the `DType` where every string longer than `max_len`
+  /// characters is cut down to `max_len` characters followed by the original
+  /// length. Nested arrays & objects are truncated recursively.
+  ///
+  /// Useful for logging or previewing large values.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let data = json!({ "bio": "Lorem ipsum dolor", "tags": ["short", "a bit longer"] });
+  /// assert_eq!(
+  ///   data.truncate_strings(5),
+  ///   json!({ "bio": "Lorem... (17 chars)", "tags": ["short", "a bit... (12 chars)"] })
+  /// );
+  /// ```
+  pub fn truncate_strings(&self, max_len: usize) -> DType {
+    walk::map(self, |_, value| match value {
+      DType::String(s) => {
+        let (end, _) = s.char_indices().nth(max_len)?;
+        Some(DType::String(format!(
+          "{}... ({} chars)",
+          &s[..end],
+          s.chars().count()
+        )))
+      }
+      _ => None,
+    })
+  }
+
+  /// Returns a copy of the `DType` without `null`s: `null` entries are
+  /// removed from objects and `null` elements from arrays, at every nesting
+  /// level. Scalars are returned as they are.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let person = json!({
+  ///   "name": "John Doe",
+  ///   "nickname": null,
+  ///   "phones": ["+44 1234567", null],
+  /// });
+  /// assert_eq!(
+  ///   person.strip_nulls(),
+  ///   json!({ "name": "John Doe", "phones": ["+44 1234567"] })
+  /// );
+  /// assert_eq!(json!(null).strip_nulls(), json!(null));
+  /// ```
+  pub fn strip_nulls(&self) -> DType {
+    self.strip(&DType::is_null)
+  }
+
+  /// Returns a copy of the `DType` with empty arrays removed from objects &
+  /// arrays, at every nesting level. An array left empty once its own empty
+  /// arrays are removed is removed too.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let movie = json!({ "title": "Avatar", "awards": [], "genres": [[]] });
+  /// assert_eq!(movie.strip_empty_arrays(), json!({ "title": "Avatar" }));
+  /// ```
+  pub fn strip_empty_arrays(&self) -> DType {
+    self.strip(&|value| value.as_array().is_some_and(Vec::is_empty))
+  }
+
+  /// Returns a copy of the `DType` with empty objects removed from objects &
+  /// arrays, at every nesting level. An object left empty once its own empty
+  /// objects are removed is removed too.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let movie = json!({ "title": "Avatar", "sequel": {}, "meta": { "x": {} } });
+  /// assert_eq!(movie.strip_empty_objects(), json!({ "title": "Avatar" }));
+  /// ```
+  pub fn strip_empty_objects(&self) -> DType {
+    self.strip(&|value| value.as_object().is_some_and(Map::is_empty))
+  }
+
+  /// Copies the `DType`, dropping nested values that match `drop` once
+  /// their own children have been stripped.
+  fn strip<F>(&self, drop: &F) -> DType
+  where
+    F: Fn(&DType) -> bool,
+  {
+    walk::strip(self, drop)
+  }
+
+  /// Renders the `DType` as indented JSON, as configured by `config`.
+  ///
+  /// Arrays & objects that fit within `config.max_width` stay on one line.
+  /// Unless `config.color` is set (with the `termcolor` feature), the output
+  /// is valid JSON and parses back into an equal `DType`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{dtype::PrettyPrintConfig, json, DType};
+  ///
+  /// let movie = json!({
+  ///   "cast": [{ "name": "Sam Worthington" }, { "name": "Zoe Saldana" }]
+  /// });
+  ///
+  /// let config = PrettyPrintConfig {
+  ///   max_width: 40,
+  ///   ..PrettyPrintConfig::default()
+  /// };
+  /// let pretty = movie.pretty_print(config);
+  /// assert_eq!(
+  ///   pretty,
+  ///   r#"{
+  ///   "cast": [
+  ///     {"name": "Sam Worthington"},
+  ///     {"name": "Zoe Saldana"}
+  ///   ]
+  /// }"#
+  /// );
+  /// assert_eq!(sage::json::from_str::<DType>(&pretty).unwrap(), movie);
+  /// ```
+  pub fn pretty_print(&self, config: PrettyPrintConfig) -> String {
+    pretty::Printer::print(self, &config)
+  }
+
+  /// Returns a copy of the `DType` where the value of every object entry
+  /// whose key is in `keys` is replaced by `"[REDACTED]"`. Nested arrays &
+  /// objects are redacted recursively.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let user = json!({ "name": "Jane", "auth": { "password": "hunter2" } });
+  /// assert_eq!(
+  ///   user.redact_keys(&["password"]),
+  ///   json!({ "name": "Jane", "auth": { "password": "[REDACTED]" } })
+  /// );
+  ///
+  /// // Redacted values are replaced whole, even arrays & objects.
+  /// let users = json!([{ "auth": { "name": "Jane" } }]);
+  /// assert_eq!(users.redact_keys(&["auth", "name"]), json!([{ "auth": "[REDACTED]" }]));
+  /// ```
+  pub fn redact_keys(&self, keys: &[&str]) -> DType {
+    walk::map(self, |key, _| {
+      key
+        .filter(|k| keys.contains(k))
+        .map(|_| DType::String("[REDACTED]".to_string()))
+    })
+  }
+
+  /// Estimates the number of bytes used by the `DType`, including the heap
+  /// allocations of its strings, arrays & objects, recursively.
+  ///
+  /// This is an estimate, not an allocator measurement: it counts
+  /// `size_of` values plus `String` & `Vec` capacities, and charges each
+  /// object entry the size of its key & value but not the map's own
+  /// bookkeeping.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let small = json!({ "name": "Sage" });
+  /// let large = json!({ "name": "Sage", "tags": ["graph", "rdf"] });
+  ///
+  /// assert!(json!(null).deep_size() > 0);
+  /// assert!(large.deep_size() > small.deep_size());
+  /// ```
+  pub fn deep_size(&self) -> usize {
+    mem::size_of::<DType>() + self.heap_size()
+  }
+
+  /// Bytes owned by the `DType` outside of its own `size_of`: the sum of
+  /// every nested value's own allocations.
+  fn heap_size(&self) -> usize {
+    walk::DepthFirst::new(self)
+      .map(|value| match value {
+        DType::Array(v) => v.capacity() * mem::size_of::<DType>(),
+        DType::Object(m) => m
+          .keys()
+          .map(|k| {
+            mem::size_of::<String>() + k.capacity() + mem::size_of::<DType>()
+          })
+          .sum(),
+        DType::String(s) => s.capacity(),
+        #[cfg(feature = "arbitrary_precision")]
+        DType::Number(n) => n.n.capacity(),
+        _ => 0,
+      })
+      .sum()
+  }
+
+  /// Returns a random sample of up to `n` elements of an `Array`, or `n`
+  /// entries of an `Object`, keeping their original order. Scalars are
+  /// returned as is.
+  ///
+  /// Only available with the `sampling` feature.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let data = json!([1, 2, 3, 4, 5]);
+  /// assert_eq!(data.sample(3).as_array().unwrap().len(), 3);
+  /// assert_eq!(data.sample(10), data);
+  ///
+  /// let obj = json!({ "a": 1, "b": 2, "c": 3 });
+  /// assert_eq!(obj.sample(2).as_object().unwrap().len(), 2);
+  ///
+  /// assert_eq!(json!("Sage").sample(1), json!("Sage"));
+  /// ```
+  #[cfg(feature = "sampling")]
+  pub fn sample(&self, n: usize) -> DType {
+    self.sample_with(n, &mut rand::thread_rng())
+  }
+
+  /// Like [`DType::sample`], but always picks the same elements for the same
+  /// `seed`, for reproducible results, whatever the map representation
+  /// (object entries are sampled in key order under `fast_map`).
+  ///
+  /// Only available with the `sampling` feature.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let data = json!({ "a": 1, "b": 2, "c": 3, "d": 4 });
+  /// assert_eq!(
+  ///   data.sample_deterministic(2, 42),
+  ///   data.sample_deterministic(2, 42)
+  /// );
+  /// ```
+  #[cfg(feature = "sampling")]
+  pub fn sample_deterministic(&self, n: usize, seed: u64) -> DType {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    self.sample_with(n, &mut StdRng::seed_from_u64(seed))
+  }
+
+  #[cfg(feature = "sampling")]
+  fn sample_with<R>(&self, n: usize, rng: &mut R) -> DType
+  where
+    R: ?Sized + rand::Rng,
+  {
+    // Sorted indices keep the sample in its original order.
+    let mut pick = |len: usize| {
+      let mut indices =
+        rand::seq::index::sample(rng, len, n.min(len)).into_vec();
+      indices.sort_unstable();
+      indices
+    };
+
+    match self {
+      DType::Array(v) => {
+        DType::Array(pick(v.len()).into_iter().map(|i| v[i].clone()).collect())
+      }
+      DType::Object(m) => {
+        #[cfg_attr(not(feature = "fast_map"), allow(unused_mut))]
+        let mut entries: Vec<_> = m.iter().collect();
+        // A `HashMap`'s iteration order changes between processes: sort so
+        // the same seed picks the same entries.
+        #[cfg(feature = "fast_map")]
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        DType::Object(
+          pick(entries.len())
+            .into_iter()
+            .map(|i| (entries[i].0.clone(), entries[i].1.clone()))
+            .collect(),
+        )
+      }
+      other => other.clone(),
+    }
+  }
+
+  /// Compresses an array of objects sharing the same keys into a columnar
+  /// `CompressedDType`, which stores each key once instead of once per
+  /// object. Use `CompressedDType::to_dtype` to decompress.
+  ///
+  /// The schema lists the keys in the first object's order: sorted by
+  /// default, in insertion order with `preserve_order`. With `fast_map`,
+  /// whose order is arbitrary, they are sorted.
+  ///
+  /// Returns `None` if the `DType` is not an array, if an element is not an
+  /// object, or if the objects don't all have the same keys.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let movies = json!([
+  ///   { "name": "Avatar", "year": 2009 },
+  ///   { "name": "Titanic", "year": 1997 },
+  /// ]);
+  ///
+  /// let compressed = movies.compress_object_array().unwrap();
+  /// assert_eq!(compressed.schema, vec!["name", "year"]);
+  /// assert_eq!(compressed.rows[1], vec![json!("Titanic"), json!(1997)]);
+  ///
+  /// assert!(json!([{ "a": 1 }, { "b": 2 }]).compress_object_array().is_none());
+  /// assert!(json!([{ "a": 1 }, 2]).compress_object_array().is_none());
+  /// ```
+  pub fn compress_object_array(&self) -> Option<CompressedDType> {
+    CompressedDType::from_array(self.as_array()?)
+  }
+
+  /// Returns the schema.org datatype `IRI` (e.g. `"schema:Text"`) matching
+  /// a scalar `DType`, for emitting typed literals.
+  ///
+  /// `Null`, `Array` & `Object` have no datatype and return `None`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// assert_eq!(json!(true).schema_org_type_iri().unwrap(), "schema:Boolean");
+  /// assert_eq!(json!(42).schema_org_type_iri().unwrap(), "schema:Integer");
+  /// assert_eq!(json!(4.2).schema_org_type_iri().unwrap(), "schema:Float");
+  /// assert_eq!(json!("Sage").schema_org_type_iri().unwrap(), "schema:Text");
+  /// assert_eq!(json!(null).schema_org_type_iri(), None);
+  /// assert_eq!(json!([1, 2]).schema_org_type_iri(), None);
+  /// ```
+  pub fn schema_org_type_iri(&self) -> Option<IRI> {
+    let term = match self {
+      DType::Boolean(_) => "Boolean",
+      DType::Number(n) if n.is_i64() || n.is_u64() => "Integer",
+      DType::Number(_) => "Float",
+      DType::String(_) => "Text",
+      DType::DateTime(_) => "DateTime",
+      DType::Null | DType::Array(_) | DType::Object(_) => return None,
+    };
+    Some(SchemaVocab::term(term))
+  }
+
   /// Looks up a value by a JSON Pointer.
   ///
   /// JSON Pointer defines a string syntax for identifying a specific value
@@ -679,6 +1320,40 @@ impl DType {
       })
   }
 
+  /// Gets the entry at a JSON Pointer path for in-place manipulation, like
+  /// [`Map::entry`] does for a single key.
+  ///
+  /// Inserting into a vacant entry creates any missing intermediate objects
+  /// (replacing `null`s on the way). Returns `None` if the pointer is
+  /// malformed, or if the path can't be created because it runs through a
+  /// scalar or past the end of an array.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// let mut data = json!({ "movie": { "title": "Avatar" } });
+  ///
+  /// data
+  ///   .entry_pointer("/movie/director/name")
+  ///   .unwrap()
+  ///   .or_insert(json!("James Cameron"));
+  /// data
+  ///   .entry_pointer("/movie/title")
+  ///   .unwrap()
+  ///   .and_modify(|t| *t = json!("Avatar (2009)"));
+  ///
+  /// assert_eq!(data["movie"]["director"]["name"], "James Cameron");
+  /// assert_eq!(data["movie"]["title"], "Avatar (2009)");
+  ///
+  /// assert!(data.entry_pointer("/movie/title/x").is_none());
+  /// assert!(data.entry_pointer("movie").is_none());
+  /// ```
+  pub fn entry_pointer(&mut self, pointer: &str) -> Option<PointerEntry<'_>> {
+    PointerEntry::new(self, pointer)
+  }
+
   /// Takes the value of the `DType`, leaving a `Null` in its place.
   ///
   /// # Example
@@ -695,6 +1370,91 @@ impl DType {
     std::mem::replace(self, DType::Null)
   }
 
+  /// Infers the type of a string value, falling back to `DType::String`
+  /// when no other type fits.
+  ///
+  /// The following are tried in order: boolean literals (`"true"` &
+  /// `"false"`), integers, finite floats and [ISO 8601] dates or datetimes.
+  /// Useful when ingesting CSV or other schema-less text data where every
+  /// value comes in as a string.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::DType;
+  ///
+  /// assert_eq!(DType::from_str_auto("true"), DType::Boolean(true));
+  /// assert_eq!(DType::from_str_auto("-42"), -42);
+  /// assert_eq!(DType::from_str_auto("2.5"), 2.5);
+  /// assert!(matches!(DType::from_str_auto("2021-05-04"), DType::DateTime(_)));
+  /// assert_eq!(DType::from_str_auto("Sage"), "Sage");
+  /// ```
+  ///
+  /// [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601
+  pub fn from_str_auto(s: &str) -> DType {
+    DType::from_str_strict(s).unwrap_or_else(|_| DType::String(s.to_owned()))
+  }
+
+  /// Like [`DType::from_str_auto`], but returns an error instead of falling
+  /// back to `DType::String` when the value isn't a boolean, number or date.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::DType;
+  ///
+  /// assert_eq!(DType::from_str_strict("false").unwrap(), false);
+  /// assert_eq!(DType::from_str_strict("1e3").unwrap(), 1000.0);
+  /// assert!(DType::from_str_strict("Sage").is_err());
+  ///
+  /// // Non-finite floats have no `Number` representation.
+  /// assert!(DType::from_str_strict("NaN").is_err());
+  /// ```
+  pub fn from_str_strict(s: &str) -> Result<DType> {
+    match s {
+      "true" => return Ok(DType::Boolean(true)),
+      "false" => return Ok(DType::Boolean(false)),
+      _ => {}
+    }
+    if let Ok(i) = s.parse::<i64>() {
+      return Ok(i.into());
+    }
+    if let Ok(u) = s.parse::<u64>() {
+      return Ok(u.into());
+    }
+    if let Some(n) = s.parse::<f64>().ok().and_then(Number::from_f64) {
+      return Ok(n.into());
+    }
+    s.parse::<DateTime>().map(DType::DateTime)
+  }
+
+  /// Starts building a `DType::Object` field by field. See [`ObjectBuilder`].
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// use sage::DType;
+  ///
+  /// let movie = DType::object().field("title", "Avatar").build();
+  /// assert_eq!(movie, json!({"title": "Avatar"}));
+  /// ```
+  pub fn object() -> ObjectBuilder {
+    ObjectBuilder::new()
+  }
+
+  /// Starts building a `DType::Array` element by element. See
+  /// [`ArrayBuilder`].
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// use sage::DType;
+  ///
+  /// let years = DType::array().push(2009).push(2022).build();
+  /// assert_eq!(years, json!([2009, 2022]));
+  /// ```
+  pub fn array() -> ArrayBuilder {
+    ArrayBuilder::new()
+  }
+
   #[cold]
   fn parse_index(s: &str) -> Option<usize> {
     if s.starts_with('+') || (s.starts_with('0') && s.len() != 1) {
@@ -739,6 +1499,40 @@ impl Default for DType {
   }
 }
 
+/// Only available with the `hashable-map` feature, which makes
+/// `Map<String, DType>` (and so `DType::Object`) hashable.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "hashable-map")]
+/// # {
+/// use std::collections::HashSet;
+///
+/// use sage::json;
+///
+/// let mut seen = HashSet::new();
+/// assert!(seen.insert(json!({ "name": "Sage", "tags": [1, 2] })));
+/// assert!(!seen.insert(json!({ "tags": [1, 2], "name": "Sage" })));
+/// assert!(seen.insert(json!(null)));
+/// # }
+/// ```
+#[cfg(feature = "hashable-map")]
+impl std::hash::Hash for DType {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    mem::discriminant(self).hash(state);
+    match self {
+      DType::Array(v) => v.hash(state),
+      DType::Boolean(b) => b.hash(state),
+      DType::DateTime(d) => d.hash(state),
+      DType::Null => {}
+      DType::Number(n) => n.hash(state),
+      DType::Object(m) => m.hash(state),
+      DType::String(s) => s.hash(state),
+    }
+  }
+}
+
 /*
  * +----------------------------------------------------------------------+
  * | +------------------------------------------------------------------+ |
@@ -787,16 +1581,27 @@ impl Default for DType {
 /// This conversion can fila if `T`'s implementation of `Serialize` decides to
 /// fail, or if `T` contains a map with non-string keys.
 ///
-/// ```rust,ignore
+/// ```rust
 /// use std::collections::BTreeMap;
 ///
-/// fn main() {
-///   // The keys in this map are vectors, not strings.
-///   let mut map = BTreeMap::new();
-///   map.insert(vec![32, 64], "x86");
+/// // The keys in this map are vectors, not strings.
+/// let mut map = BTreeMap::new();
+/// map.insert(vec![32, 64], "x86");
 ///
-///   println!("{}", sage::to_value(map).unwrap());
-/// }
+/// assert!(sage::to_dtype(map).is_err());
+/// ```
+///
+/// # Lossy conversions
+///
+/// `DType` cannot represent `NaN` or infinite floats, so these silently become
+/// `DType::Null`. Use [`to_dtype_strict`] to get an error instead.
+///
+/// ```rust
+/// # use sage::DType;
+/// #
+/// assert_eq!(sage::to_dtype(f64::NAN).unwrap(), DType::Null);
+/// assert_eq!(sage::to_dtype(f64::INFINITY).unwrap(), DType::Null);
+/// assert_eq!(sage::to_dtype(f64::NEG_INFINITY).unwrap(), DType::Null);
 /// ```
 pub fn to_dtype<T>(value: T) -> Result<DType>
 where
@@ -805,6 +1610,42 @@ where
   value.serialize(Serializer)
 }
 
+/// Convert a `T` into `sage::DType`, failing on values that `DType` cannot
+/// represent instead of degrading them.
+///
+/// Unlike [`to_dtype`], `NaN` and infinite floats are an error, as are
+/// `i128`/`u128` values that don't fit into `i64`/`u64` (unless the
+/// `arbitrary_precision` feature is enabled). The error's [`path`] points at
+/// the offending value.
+///
+/// [`path`]: crate::Error::path
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+///   let mut map = BTreeMap::new();
+///   map.insert("x", x);
+///
+///   let err = sage::to_dtype_strict(&map).unwrap_err();
+///   assert!(err.is_data());
+///   assert_eq!(
+///     err.to_string(),
+///     "non-finite float cannot be represented at `/x`"
+///   );
+/// }
+///
+/// assert_eq!(sage::to_dtype_strict(1.5).unwrap(), sage::json!(1.5));
+/// ```
+pub fn to_dtype_strict<T>(value: T) -> Result<DType>
+where
+  T: Serialize,
+{
+  value.serialize(StrictSerializer)
+}
+
 /// Interpret a `sage::DType` as an instance of type `T`.
 ///
 /// # Example
@@ -0,0 +1,218 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builders for `DType` objects & arrays whose keys or values are computed,
+//! where the `json!` macro doesn't fit.
+
+use crate::{DType, Map};
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `ObjectBuilder`.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// Builds a `DType::Object` one field at a time.
+///
+/// This struct is constructed from [`DType::object`]. Fields keep their
+/// insertion order when the `preserve_order` feature is enabled, and a later
+/// field with the same key replaces an earlier one.
+///
+/// # Example
+///
+/// ```rust
+/// # use sage::json;
+/// use sage::DType;
+///
+/// let nickname: Option<&str> = None;
+/// let verified = true;
+///
+/// let person = DType::object()
+///   .field("name", "John Doe")
+///   .field("age", 43)
+///   .field_opt("nickname", nickname)
+///   .field_if(verified, "verified", true)
+///   .build();
+///
+/// assert_eq!(
+///   person,
+///   json!({"name": "John Doe", "age": 43, "verified": true})
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ObjectBuilder {
+  map: Map<String, DType>,
+}
+
+impl ObjectBuilder {
+  /// Creates an empty builder.
+  pub fn new() -> ObjectBuilder {
+    ObjectBuilder { map: Map::new() }
+  }
+
+  /// Creates an empty builder with room for `capacity` fields.
+  pub fn with_capacity(capacity: usize) -> ObjectBuilder {
+    ObjectBuilder {
+      map: Map::with_capacity(capacity),
+    }
+  }
+
+  /// Sets `key` to `value`.
+  pub fn field<K, V>(mut self, key: K, value: V) -> ObjectBuilder
+  where
+    K: Into<String>,
+    V: Into<DType>,
+  {
+    self.map.insert(key.into(), value.into());
+    self
+  }
+
+  /// Sets `key` to `value` only when `condition` holds.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// use sage::DType;
+  ///
+  /// let adult = |age: u64| {
+  ///   DType::object()
+  ///     .field("age", age)
+  ///     .field_if(age >= 18, "adult", true)
+  ///     .build()
+  /// };
+  ///
+  /// assert_eq!(adult(43), json!({"age": 43, "adult": true}));
+  /// assert_eq!(adult(12), json!({"age": 12}));
+  /// ```
+  pub fn field_if<K, V>(
+    self,
+    condition: bool,
+    key: K,
+    value: V,
+  ) -> ObjectBuilder
+  where
+    K: Into<String>,
+    V: Into<DType>,
+  {
+    if condition {
+      self.field(key, value)
+    } else {
+      self
+    }
+  }
+
+  /// Sets `key` to the value in `value`, skipping the field if it's `None`.
+  pub fn field_opt<K, V>(self, key: K, value: Option<V>) -> ObjectBuilder
+  where
+    K: Into<String>,
+    V: Into<DType>,
+  {
+    match value {
+      Some(value) => self.field(key, value),
+      None => self,
+    }
+  }
+
+  /// Sets every field of `map`, replacing fields already set.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// use sage::{DType, Map};
+  ///
+  /// let mut defaults = Map::new();
+  /// defaults.insert("lang".to_string(), json!("en"));
+  ///
+  /// let doc = DType::object().field("lang", "fr").merge(defaults).build();
+  /// assert_eq!(doc, json!({"lang": "en"}));
+  /// ```
+  pub fn merge(mut self, map: Map<String, DType>) -> ObjectBuilder {
+    self.map.extend(map);
+    self
+  }
+
+  /// Returns the built `DType::Object`.
+  pub fn build(self) -> DType {
+    DType::Object(self.map)
+  }
+}
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `ArrayBuilder`.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// Builds a `DType::Array` one element at a time.
+///
+/// This struct is constructed from [`DType::array`].
+///
+/// # Example
+///
+/// ```rust
+/// # use sage::json;
+/// use sage::DType;
+///
+/// let genres = DType::array()
+///   .push("Action")
+///   .extend(vec!["Adventure", "Fantasy"])
+///   .build();
+///
+/// assert_eq!(genres, json!(["Action", "Adventure", "Fantasy"]));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ArrayBuilder {
+  vec: Vec<DType>,
+}
+
+impl ArrayBuilder {
+  /// Creates an empty builder.
+  pub fn new() -> ArrayBuilder {
+    ArrayBuilder { vec: Vec::new() }
+  }
+
+  /// Creates an empty builder with room for `capacity` elements.
+  pub fn with_capacity(capacity: usize) -> ArrayBuilder {
+    ArrayBuilder {
+      vec: Vec::with_capacity(capacity),
+    }
+  }
+
+  /// Appends `value`.
+  pub fn push<V: Into<DType>>(mut self, value: V) -> ArrayBuilder {
+    self.vec.push(value.into());
+    self
+  }
+
+  /// Appends every element of `values`.
+  pub fn extend<I>(mut self, values: I) -> ArrayBuilder
+  where
+    I: IntoIterator,
+    I::Item: Into<DType>,
+  {
+    self.vec.extend(values.into_iter().map(Into::into));
+    self
+  }
+
+  /// Returns the built `DType::Array`.
+  pub fn build(self) -> DType {
+    DType::Array(self.vec)
+  }
+}
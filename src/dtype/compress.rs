@@ -0,0 +1,109 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A columnar representation of arrays of same-shaped objects.
+//!
+//! An array such as `[{"name": "a", "age": 1}, {"name": "b", "age": 2}]`
+//! repeats every key in every object. [`CompressedDType`] stores the keys
+//! once, in `schema`, and each object as a row of values in schema order.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{DType, Map};
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `CompressedDType`.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// An array of objects sharing the same keys, stored column-wise.
+///
+/// This struct is constructed from [`DType::compress_object_array`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompressedDType {
+  /// The keys shared by every object, in the first object's key order
+  /// (sorted with `fast_map`, whose order is arbitrary).
+  pub schema: Vec<String>,
+  /// One row per object, holding its values in `schema` order.
+  pub rows: Vec<Vec<DType>>,
+}
+
+impl CompressedDType {
+  /// Compresses `array`, returning `None` if any element is not an object
+  /// or the objects don't all have the same keys.
+  pub(crate) fn from_array(array: &[DType]) -> Option<CompressedDType> {
+    #[allow(unused_mut)]
+    let mut schema: Vec<String> = match array.first() {
+      Some(first) => first.as_object()?.keys().cloned().collect(),
+      None => return Some(CompressedDType::default()),
+    };
+    // Sort the keys so the schema doesn't depend on the map's iteration
+    // order, which is random with the "fast_map" feature.
+    #[cfg(feature = "fast_map")]
+    schema.sort_unstable();
+
+    let rows = array
+      .iter()
+      .map(|value| {
+        let object = value.as_object()?;
+        if object.len() != schema.len() {
+          return None;
+        }
+        schema.iter().map(|key| object.get(key).cloned()).collect()
+      })
+      .collect::<Option<Vec<Vec<DType>>>>()?;
+
+    Some(CompressedDType { schema, rows })
+  }
+
+  /// Decompresses back into an array of objects.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// let people = json!([
+  ///   { "name": "Ada", "born": 1815 },
+  ///   { "name": "Alan", "born": 1912 },
+  /// ]);
+  ///
+  /// let compressed = people.compress_object_array().unwrap();
+  /// assert_eq!(compressed.to_dtype(), people);
+  ///
+  /// # #[cfg(feature = "preserve_order")]
+  /// # {
+  /// // With "preserve_order", every object keeps its key order.
+  /// let compressed = people.compress_object_array().unwrap();
+  /// assert_eq!(compressed.schema, ["name", "born"]);
+  /// let ada = &compressed.to_dtype()[0];
+  /// assert_eq!(ada.as_object().unwrap().keys().collect::<Vec<_>>(), ["name", "born"]);
+  /// # }
+  /// ```
+  pub fn to_dtype(self) -> DType {
+    let CompressedDType { schema, rows } = self;
+    DType::Array(
+      rows
+        .into_iter()
+        .map(|row| {
+          let object: Map<String, DType> =
+            schema.iter().cloned().zip(row).collect();
+          DType::Object(object)
+        })
+        .collect(),
+    )
+  }
+}
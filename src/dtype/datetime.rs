@@ -16,10 +16,52 @@
 //!
 //! By default `sage::DType::DateTime` uses Utc timezone.
 //!
+//! # Interoperability
+//!
+//! `DateTime` converts to and from [`chrono::DateTime<Utc>`] and
+//! [`chrono::DateTime<FixedOffset>`], and (with the `time` feature) to and
+//! from [`time::OffsetDateTime`]. The conversions follow these rules:
+//!
+//! - **Precision:** nanoseconds are kept in both directions.
+//! - **Offsets:** a `DateTime` is always in Utc. Values with a non-Utc offset
+//!   are converted to the same instant in Utc, so the instant is kept but the
+//!   original offset is not. Converting back gives a value with a `+00:00`
+//!   offset.
+//! - **Range:** every `chrono` value fits in a `DateTime`. The `time` crate
+//!   supports a different range, so its conversions are `TryFrom` and fail
+//!   on values that don't fit.
+//!
+//! Types from other crates serialize `DateTime`s as plain strings, so
+//! [`sage::to_dtype`] turns them into `DType::String`. Use the [`as_datetime`]
+//! module with `#[serde(with = "...")]` to get a `DType::DateTime` instead.
+//!
+//! [`chrono::DateTime<Utc>`]: chrono::DateTime
+//! [`chrono::DateTime<FixedOffset>`]: chrono::DateTime
+//! [`time::OffsetDateTime`]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+//! [`sage::to_dtype`]: crate::to_dtype
 
 // Confusing `sage::DateTime` & `chrono::DateTime`.
+use std::{
+  fmt,
+  ops::{Add, Sub},
+  str::FromStr,
+};
+
 use chrono::{prelude::*, DateTime as ChronoDateTime};
 
+use serde::{de, ser};
+
+use crate::error::{Error, ErrorCode};
+
+/// Newtype struct name marking a serialized `DateTime`.
+///
+/// Serializers which don't know about it see a plain newtype around an
+/// [RFC 3339] string. The `DType` serializer uses it to output
+/// `DType::DateTime` instead of `DType::String`.
+///
+/// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+pub(crate) const TOKEN: &str = "$sage::private::DateTime";
+
 /*
 * +----------------------------------------------------------------------+
 * | +------------------------------------------------------------------+ |
@@ -28,10 +70,438 @@ use chrono::{prelude::*, DateTime as ChronoDateTime};
 * +----------------------------------------------------------------------+
 */
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct DateTime {
   d: DateTimeImpl,
 }
 
 // Default timezone is Utc.
 type DateTimeImpl = ChronoDateTime<Utc>;
+
+const SECS_PER_DAY: i64 = 86_400;
+
+impl DateTime {
+  /// Creates a `DateTime` from the number of (non-leap) seconds since
+  /// `1970-01-01T00:00:00Z`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `ts` is outside of the supported range (about ±262,000 years).
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::DateTime;
+  ///
+  /// let d = DateTime::from_unix(1_620_117_000);
+  /// assert_eq!(d.to_string(), "2021-05-04T08:30:00Z");
+  /// assert_eq!(d.unix_timestamp(), 1_620_117_000);
+  /// ```
+  pub fn from_unix(ts: i64) -> DateTime {
+    DateTime {
+      d: Utc
+        .timestamp_opt(ts, 0)
+        .single()
+        .expect("unix timestamp out of range"),
+    }
+  }
+
+  /// Returns the number of (non-leap) seconds since `1970-01-01T00:00:00Z`.
+  /// Sub-second precision is dropped.
+  pub fn unix_timestamp(&self) -> i64 {
+    self.d.timestamp()
+  }
+
+  /// Returns a new `DateTime` shifted by the given number of days & seconds.
+  /// Either may be negative.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the result is outside of the supported range.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::DateTime;
+  ///
+  /// let d = DateTime::from_unix(0).add_duration(1, -60);
+  /// assert_eq!(d.to_string(), "1970-01-01T23:59:00Z");
+  /// ```
+  pub fn add_duration(&self, days: i64, secs: i64) -> DateTime {
+    self.clone() + Duration::new(days, secs)
+  }
+
+  /// Returns the time elapsed from `other` to `self`, negative if `self` is
+  /// earlier. Sub-second precision is dropped.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{dtype::datetime::Duration, DateTime};
+  ///
+  /// let a = DateTime::from_unix(0);
+  /// let b = a.add_duration(2, 90);
+  /// assert_eq!(b.diff(&a), Duration { days: 2, seconds: 90 });
+  /// assert_eq!(a.diff(&b), Duration { days: -3, seconds: 86_310 });
+  /// ```
+  pub fn diff(&self, other: &DateTime) -> Duration {
+    Duration::new(0, (self.d - other.d).num_seconds())
+  }
+
+  /// Returns true if `self` is strictly earlier than `other`.
+  pub fn is_before(&self, other: &DateTime) -> bool {
+    self < other
+  }
+}
+
+/// A span of time, in whole days & seconds.
+///
+/// `Duration::new` normalizes its value so that `seconds` is always within
+/// `0..86_400` and `days` carries the sign, e.g. minus one second is
+/// `Duration { days: -1, seconds: 86_399 }`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Duration {
+  /// Number of whole days.
+  pub days: i64,
+  /// Number of seconds on top of `days`.
+  pub seconds: i64,
+}
+
+impl Duration {
+  /// Creates a normalized `Duration` from days & seconds, either of which
+  /// may be negative or exceed a day.
+  pub fn new(days: i64, seconds: i64) -> Duration {
+    Duration {
+      days: days + seconds.div_euclid(SECS_PER_DAY),
+      seconds: seconds.rem_euclid(SECS_PER_DAY),
+    }
+  }
+
+  fn to_chrono(self) -> chrono::Duration {
+    chrono::Duration::days(self.days) + chrono::Duration::seconds(self.seconds)
+  }
+}
+
+impl Add<Duration> for DateTime {
+  type Output = DateTime;
+
+  fn add(self, rhs: Duration) -> DateTime {
+    DateTime {
+      d: self.d + rhs.to_chrono(),
+    }
+  }
+}
+
+impl Sub<Duration> for DateTime {
+  type Output = DateTime;
+
+  fn sub(self, rhs: Duration) -> DateTime {
+    DateTime {
+      d: self.d - rhs.to_chrono(),
+    }
+  }
+}
+
+impl fmt::Display for DateTime {
+  /// Formats the `DateTime` as an [RFC 3339] string,
+  /// e.g. `"2021-05-04T08:30:00Z"`.
+  ///
+  /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&self.d.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+  }
+}
+
+impl FromStr for DateTime {
+  type Err = Error;
+
+  /// Parses an [ISO 8601] date or datetime string.
+  ///
+  /// Accepts full [RFC 3339] datetimes (`"2021-05-04T08:30:00+01:00"`),
+  /// datetimes without an offset (`"2021-05-04T08:30:00"`) and plain dates
+  /// (`"2021-05-04"`). Values without an offset are taken to be in Utc and
+  /// plain dates start at midnight.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::DateTime;
+  ///
+  /// let d: DateTime = "2021-05-04".parse().unwrap();
+  /// assert_eq!(d.to_string(), "2021-05-04T00:00:00Z");
+  ///
+  /// let d: DateTime = "2021-05-04T08:30:00+01:00".parse().unwrap();
+  /// assert_eq!(d.to_string(), "2021-05-04T07:30:00Z");
+  ///
+  /// assert!("yesterday".parse::<DateTime>().is_err());
+  /// ```
+  ///
+  /// [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601
+  /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    // RFC 3339 has no room for years outside of 0000-9999, which chrono
+    // formats with a sign (e.g. `-9999-01-01T00:00:00Z`).
+    let naive = s.strip_suffix('Z').unwrap_or(s);
+    let d = if let Ok(d) = ChronoDateTime::parse_from_rfc3339(s) {
+      d.with_timezone(&Utc)
+    } else if let Ok(d) =
+      NaiveDateTime::parse_from_str(naive, "%Y-%m-%dT%H:%M:%S%.f")
+    {
+      Utc.from_utc_datetime(&d)
+    } else if let Ok(d) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+      Utc.from_utc_datetime(&d.and_time(NaiveTime::MIN))
+    } else {
+      return Err(sage_err!(ErrorCode::ParseError, "invalid datetime {:?}", s));
+    };
+    Ok(DateTime { d })
+  }
+}
+
+impl ser::Serialize for DateTime {
+  #[inline]
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: ser::Serializer,
+  {
+    serializer.serialize_newtype_struct(TOKEN, &self.to_string())
+  }
+}
+
+impl<'de> de::Deserialize<'de> for DateTime {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: de::Deserializer<'de>,
+  {
+    struct DateTimeVisitor;
+
+    impl<'de> de::Visitor<'de> for DateTimeVisitor {
+      type Value = DateTime;
+
+      fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ISO 8601 date or datetime string")
+      }
+
+      fn visit_str<E>(self, s: &str) -> Result<DateTime, E>
+      where
+        E: de::Error,
+      {
+        s.parse()
+          .map_err(|_| E::invalid_value(de::Unexpected::Str(s), &self))
+      }
+    }
+
+    deserializer.deserialize_str(DateTimeVisitor)
+  }
+}
+
+/*
+* +----------------------------------------------------------------------+
+* | +------------------------------------------------------------------+ |
+* | | `chrono` conversions.
+* | +------------------------------------------------------------------+ |
+* +----------------------------------------------------------------------+
+*/
+
+impl From<ChronoDateTime<Utc>> for DateTime {
+  /// Wraps a `chrono::DateTime<Utc>`. Nothing is lost.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use chrono::{TimeZone, Utc};
+  /// use sage::DateTime;
+  ///
+  /// let c = Utc.timestamp_opt(1_620_117_000, 123_456_789).unwrap();
+  /// let d = DateTime::from(c);
+  /// assert_eq!(chrono::DateTime::<Utc>::from(d), c);
+  /// ```
+  fn from(d: ChronoDateTime<Utc>) -> Self {
+    DateTime { d }
+  }
+}
+
+impl From<ChronoDateTime<FixedOffset>> for DateTime {
+  /// Converts to the same instant in Utc. The original offset is dropped.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use chrono::DateTime as ChronoDateTime;
+  /// use sage::DateTime;
+  ///
+  /// let c = ChronoDateTime::parse_from_rfc3339("2021-05-04T08:30:00.5-07:00").unwrap();
+  /// let d = DateTime::from(c);
+  /// assert_eq!(d.to_string(), "2021-05-04T15:30:00.500Z");
+  ///
+  /// // Same instant, Utc offset.
+  /// let back: ChronoDateTime<chrono::FixedOffset> = d.into();
+  /// assert_eq!(back, c);
+  /// assert_eq!(back.offset().local_minus_utc(), 0);
+  /// ```
+  fn from(d: ChronoDateTime<FixedOffset>) -> Self {
+    DateTime {
+      d: d.with_timezone(&Utc),
+    }
+  }
+}
+
+impl From<DateTime> for ChronoDateTime<Utc> {
+  fn from(d: DateTime) -> Self {
+    d.d
+  }
+}
+
+impl From<DateTime> for ChronoDateTime<FixedOffset> {
+  fn from(d: DateTime) -> Self {
+    d.d.into()
+  }
+}
+
+/*
+* +----------------------------------------------------------------------+
+* | +------------------------------------------------------------------+ |
+* | | `time` conversions.
+* | +------------------------------------------------------------------+ |
+* +----------------------------------------------------------------------+
+*/
+
+#[cfg(feature = "time")]
+impl std::convert::TryFrom<time::OffsetDateTime> for DateTime {
+  type Error = Error;
+
+  /// Converts to the same instant in Utc, keeping nanosecond precision.
+  /// Fails if the instant is outside of `chrono`'s supported range.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # #[cfg(feature = "time")]
+  /// # {
+  /// use std::convert::TryFrom;
+  /// use sage::DateTime;
+  /// use time::{OffsetDateTime, UtcOffset};
+  ///
+  /// let t = OffsetDateTime::from_unix_timestamp_nanos(1_620_117_000_123_456_789)
+  ///   .unwrap()
+  ///   .to_offset(UtcOffset::from_hms(5, 30, 0).unwrap());
+  /// let d = DateTime::try_from(t).unwrap();
+  /// assert_eq!(d.to_string(), "2021-05-04T08:30:00.123456789Z");
+  ///
+  /// // Same instant, Utc offset.
+  /// let back = OffsetDateTime::try_from(d).unwrap();
+  /// assert_eq!(back, t);
+  /// assert!(back.offset().is_utc());
+  ///
+  /// // Extreme dates.
+  /// let min = DateTime::try_from(OffsetDateTime::from_unix_timestamp(-377_705_116_800).unwrap());
+  /// assert_eq!(min.unwrap().to_string(), "-9999-01-01T00:00:00Z");
+  ///
+  /// // Beyond the `time` crate's default range.
+  /// let far: DateTime = "+10000-01-01T00:00:00Z".parse().unwrap();
+  /// assert!(OffsetDateTime::try_from(far).is_err());
+  /// # }
+  /// ```
+  fn try_from(t: time::OffsetDateTime) -> Result<Self, Self::Error> {
+    let nanos = t.unix_timestamp_nanos();
+    let secs = nanos.div_euclid(1_000_000_000);
+    let subsec = nanos.rem_euclid(1_000_000_000) as u32;
+    i64::try_from(secs)
+      .ok()
+      .and_then(|secs| Utc.timestamp_opt(secs, subsec).single())
+      .map(|d| DateTime { d })
+      .ok_or_else(|| Error::syntax(ErrorCode::NumberOutOfRange, 0, 0))
+  }
+}
+
+#[cfg(feature = "time")]
+impl std::convert::TryFrom<DateTime> for time::OffsetDateTime {
+  type Error = Error;
+
+  /// Converts to a `time::OffsetDateTime` with a Utc offset, keeping
+  /// nanosecond precision. Fails if the year is outside of the range
+  /// supported by the `time` crate (±9999 by default).
+  fn try_from(d: DateTime) -> Result<Self, Self::Error> {
+    let nanos = i128::from(d.d.timestamp()) * 1_000_000_000
+      + i128::from(d.d.timestamp_subsec_nanos());
+    time::OffsetDateTime::from_unix_timestamp_nanos(nanos)
+      .map_err(|_| Error::syntax(ErrorCode::NumberOutOfRange, 0, 0))
+  }
+}
+
+/*
+* +----------------------------------------------------------------------+
+* | +------------------------------------------------------------------+ |
+* | | `as_datetime` serde helper.
+* | +------------------------------------------------------------------+ |
+* +----------------------------------------------------------------------+
+*/
+
+/// Serialize & deserialize foreign datetime types as a `sage::DateTime`.
+///
+/// Use it with `#[serde(with = "sage::dtype::datetime::as_datetime")]` on a
+/// field of any type that converts to and from `sage::DateTime`, e.g.
+/// `chrono::DateTime<Utc>` or (with the `time` feature)
+/// `time::OffsetDateTime`. [`sage::to_dtype`] then outputs `DType::DateTime`
+/// for the field rather than `DType::String`. Other serializers still see an
+/// [RFC 3339] string.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use serde_derive::{Deserialize, Serialize};
+/// use sage::{from_dtype, to_dtype, DType};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Event {
+///   #[serde(with = "sage::dtype::datetime::as_datetime")]
+///   at: chrono::DateTime<Utc>,
+/// }
+///
+/// // Extreme dates survive the round-trip.
+/// for at in [
+///   Utc.with_ymd_and_hms(-9999, 1, 1, 0, 0, 0).unwrap(),
+///   Utc.with_ymd_and_hms(9999, 12, 31, 23, 59, 59).unwrap(),
+///   Utc.timestamp_opt(0, 1).unwrap(),
+/// ] {
+///   let event = Event { at };
+///   let dtype = to_dtype(&event).unwrap();
+///   assert!(matches!(dtype["at"], DType::DateTime(_)));
+///   assert_eq!(from_dtype::<Event>(dtype).unwrap(), event);
+/// }
+/// ```
+///
+/// [`sage::to_dtype`]: crate::to_dtype
+/// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+pub mod as_datetime {
+  use std::{
+    convert::{TryFrom, TryInto},
+    fmt::Display,
+  };
+
+  use serde::{de, ser, Deserialize, Serialize};
+
+  use super::DateTime;
+
+  /// Serializes `value` as a `sage::DateTime`.
+  pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    T: Clone + TryInto<DateTime>,
+    T::Error: Display,
+    S: ser::Serializer,
+  {
+    let d: DateTime = value.clone().try_into().map_err(ser::Error::custom)?;
+    d.serialize(serializer)
+  }
+
+  /// Deserializes a `sage::DateTime` and converts it into `T`.
+  pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+  where
+    T: TryFrom<DateTime>,
+    T::Error: Display,
+    D: de::Deserializer<'de>,
+  {
+    let d = DateTime::deserialize(deserializer)?;
+    T::try_from(d).map_err(de::Error::custom)
+  }
+}
@@ -0,0 +1,412 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Arbitrary precision decimal numbers for `sage::Number`.
+//!
+//! Only available with the `arbitrary_precision` feature, where a `Number`
+//! keeps the original text of a JSON number. [`Decimal`] lets you do exact
+//! arithmetic on that text without losing precision through `f64`.
+
+use std::{cmp::Ordering, fmt, ops::Neg, str::FromStr};
+
+use crate::error::{Error, ErrorCode};
+
+/// An exact decimal number of arbitrary size: `digits × 10^exp`.
+///
+/// A `Decimal` is always normalized, so equal values have the same
+/// representation no matter how they were written (`1e2`, `100`, `100.0`).
+///
+/// Arithmetic is checked: it returns `None` instead of overflowing the
+/// exponent, and adding or subtracting values whose exponents are more than
+/// [`MAX_EXP_GAP`] apart (e.g. `1e1000000000` and `1`) returns `None` rather
+/// than allocating memory proportional to the gap.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "arbitrary_precision")]
+/// # {
+/// use sage::dtype::decimal::Decimal;
+///
+/// let a: Decimal = "0.1".parse().unwrap();
+/// let b: Decimal = "0.2".parse().unwrap();
+/// assert_eq!(a.checked_add(&b).unwrap().to_string(), "0.3");
+///
+/// let big: Decimal = "1e40".parse().unwrap();
+/// let one: Decimal = "1".parse().unwrap();
+/// assert_eq!(big.checked_add(&one).unwrap().to_string(), "10000000000000000000000000000000000000001");
+///
+/// assert_eq!("1e2".parse::<Decimal>().unwrap(), "100.00".parse().unwrap());
+///
+/// // Out of range exponents are rejected instead of overflowing.
+/// assert!("10e9223372036854775807".parse::<Decimal>().is_err());
+/// let huge: Decimal = "1e1000000000000".parse().unwrap();
+/// assert!(huge.checked_add(&one).is_none());
+/// assert!(huge.checked_mul(&"1e9223372036854775000".parse().unwrap()).is_none());
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Decimal {
+  negative: bool,
+  /// Most significant digit first, without leading or trailing zeros.
+  /// Empty for zero.
+  digits: Vec<u8>,
+  /// Always chosen so that `exp + digits.len()` fits in an `i64`.
+  exp: i64,
+}
+
+/// The largest difference between two exponents that
+/// [`Decimal::checked_add`] & [`Decimal::checked_sub`] will align, i.e. the
+/// number of zeros they are willing to pad a mantissa with.
+pub const MAX_EXP_GAP: u64 = 1 << 20;
+
+impl Decimal {
+  /// Normalizes `digits × 10^exp`, returning `None` if the exponent
+  /// overflows.
+  fn new(negative: bool, mut digits: Vec<u8>, exp: i64) -> Option<Decimal> {
+    trim_leading_zeros(&mut digits);
+    let trailing = digits.iter().rev().take_while(|&&d| d == 0).count();
+    digits.truncate(digits.len() - trailing);
+    if digits.is_empty() {
+      return Some(Decimal::zero());
+    }
+    let exp = exp.checked_add(i64::try_from(trailing).ok()?)?;
+    exp.checked_add(i64::try_from(digits.len()).ok()?)?;
+    Some(Decimal {
+      negative,
+      digits,
+      exp,
+    })
+  }
+
+  /// Returns a `Decimal` equal to zero.
+  pub fn zero() -> Decimal {
+    Decimal {
+      negative: false,
+      digits: Vec::new(),
+      exp: 0,
+    }
+  }
+
+  /// Returns true if the `Decimal` is zero.
+  pub fn is_zero(&self) -> bool {
+    self.digits.is_empty()
+  }
+
+  /// Returns true if the `Decimal` is less than zero.
+  pub fn is_negative(&self) -> bool {
+    self.negative
+  }
+
+  /// Returns true if the `Decimal` has no fractional part.
+  pub fn is_integer(&self) -> bool {
+    self.exp >= 0
+  }
+
+  /// Adds `other` to `self`, returning `None` if the exponent overflows or
+  /// the exponents are more than [`MAX_EXP_GAP`] apart.
+  pub fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+    let (a, b, exp) = self.aligned(other)?;
+    if self.negative == other.negative {
+      return Decimal::new(self.negative, add_int(&a, &b), exp);
+    }
+    match cmp_int(&a, &b) {
+      Ordering::Equal => Some(Decimal::zero()),
+      Ordering::Greater => Decimal::new(self.negative, sub_int(&a, &b), exp),
+      Ordering::Less => Decimal::new(other.negative, sub_int(&b, &a), exp),
+    }
+  }
+
+  /// Subtracts `other` from `self`, returning `None` if the exponent
+  /// overflows or the exponents are more than [`MAX_EXP_GAP`] apart.
+  pub fn checked_sub(&self, other: &Decimal) -> Option<Decimal> {
+    self.checked_add(&-other.clone())
+  }
+
+  /// Multiplies `self` by `other`, returning `None` if the exponent
+  /// overflows.
+  pub fn checked_mul(&self, other: &Decimal) -> Option<Decimal> {
+    if self.is_zero() || other.is_zero() {
+      return Some(Decimal::zero());
+    }
+    let exp = self.exp.checked_add(other.exp)?;
+    // Schoolbook multiplication, least significant digit first.
+    let mut acc = vec![0u64; self.digits.len() + other.digits.len()];
+    for (i, &a) in self.digits.iter().rev().enumerate() {
+      for (j, &b) in other.digits.iter().rev().enumerate() {
+        acc[i + j] += u64::from(a) * u64::from(b);
+      }
+    }
+    let mut carry = 0;
+    for d in acc.iter_mut() {
+      *d += carry;
+      carry = *d / 10;
+      *d %= 10;
+    }
+    let digits = acc.iter().rev().map(|&d| d as u8).collect();
+    Decimal::new(self.negative != other.negative, digits, exp)
+  }
+
+  /// Divides `self` by `other`, returning `None` if `other` is zero, if the
+  /// exponent overflows or if the quotient has no finite decimal expansion
+  /// (e.g. `1 / 3`).
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # #[cfg(feature = "arbitrary_precision")]
+  /// # {
+  /// use sage::dtype::decimal::Decimal;
+  ///
+  /// let one: Decimal = "1".parse().unwrap();
+  /// let eight: Decimal = "8".parse().unwrap();
+  /// assert_eq!(one.div_exact(&eight).unwrap().to_string(), "0.125");
+  /// assert!(one.div_exact(&"3".parse().unwrap()).is_none());
+  /// assert!(one.div_exact(&Decimal::zero()).is_none());
+  /// # }
+  /// ```
+  pub fn div_exact(&self, other: &Decimal) -> Option<Decimal> {
+    if other.is_zero() {
+      return None;
+    }
+    // A quotient with a finite expansion needs at most as many extra digits
+    // as the largest power of 2 or 5 dividing the divisor's mantissa, which
+    // is bounded by log2 of the mantissa: < 4 bits per decimal digit.
+    let max_extra = 4 * other.digits.len() + 1;
+
+    let mut quotient = Vec::with_capacity(self.digits.len() + max_extra);
+    let mut rem: Vec<u8> = Vec::new();
+    let mut extra = 0;
+    let mut digits = self.digits.iter().copied();
+
+    loop {
+      let next = match digits.next() {
+        Some(d) => d,
+        None if rem.is_empty() => break,
+        None if extra < max_extra => {
+          extra += 1;
+          0
+        }
+        None => return None,
+      };
+      rem.push(next);
+      trim_leading_zeros(&mut rem);
+
+      let mut q = 0;
+      while cmp_int(&rem, &other.digits) != Ordering::Less {
+        rem = sub_int(&rem, &other.digits);
+        q += 1;
+      }
+      quotient.push(q);
+    }
+
+    let exp = self.exp.checked_sub(other.exp)?.checked_sub(extra as i64)?;
+    Decimal::new(self.negative != other.negative, quotient, exp)
+  }
+
+  /// Compares the absolute values of two decimals.
+  fn cmp_abs(&self, other: &Decimal) -> Ordering {
+    match (self.is_zero(), other.is_zero()) {
+      (true, true) => return Ordering::Equal,
+      (true, false) => return Ordering::Less,
+      (false, true) => return Ordering::Greater,
+      _ => {}
+    }
+    let a = self.exp + self.digits.len() as i64;
+    let b = other.exp + other.digits.len() as i64;
+    a.cmp(&b).then_with(|| self.digits.cmp(&other.digits))
+  }
+
+  /// Mantissas of `self` & `other` scaled to their common exponent, or
+  /// `None` if that needs more than [`MAX_EXP_GAP`] zeros of padding.
+  fn aligned(&self, other: &Decimal) -> Option<(Vec<u8>, Vec<u8>, i64)> {
+    // Zero has no digits to pad, so it never widens the gap.
+    let exp = match (self.is_zero(), other.is_zero()) {
+      (true, _) => other.exp,
+      (_, true) => self.exp,
+      _ => self.exp.min(other.exp),
+    };
+    let scale = |d: &Decimal| {
+      let mut v = d.digits.clone();
+      if !v.is_empty() {
+        let gap = d.exp.abs_diff(exp);
+        if gap > MAX_EXP_GAP {
+          return None;
+        }
+        v.resize(v.len() + gap as usize, 0);
+      }
+      Some(v)
+    };
+    Some((scale(self)?, scale(other)?, exp))
+  }
+}
+
+impl FromStr for Decimal {
+  type Err = Error;
+
+  /// Parses a JSON number, e.g. `-12.5e-3`.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || Error::syntax(ErrorCode::InvalidNumber, 0, 0);
+
+    let (negative, s) = match s.strip_prefix('-') {
+      Some(rest) => (true, rest),
+      None => (false, s),
+    };
+    let (mantissa, exp) = match s.find(['e', 'E']) {
+      Some(i) => {
+        let exp = &s[i + 1..];
+        let exp = exp.strip_prefix('+').unwrap_or(exp);
+        (&s[..i], exp.parse::<i64>().map_err(|_| invalid())?)
+      }
+      None => (s, 0),
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if int.is_empty() || !is_digits(int) || !is_digits(frac) {
+      return Err(invalid());
+    }
+    if mantissa.contains('.') && frac.is_empty() {
+      return Err(invalid());
+    }
+
+    let digits = int.bytes().chain(frac.bytes()).map(|b| b - b'0').collect();
+    let exp = exp.checked_sub(frac.len() as i64).ok_or_else(invalid)?;
+    Decimal::new(negative, digits, exp).ok_or_else(invalid)
+  }
+}
+
+impl fmt::Display for Decimal {
+  /// Plain notation for moderately sized values, scientific otherwise.
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if self.is_zero() {
+      return f.write_str("0");
+    }
+    if self.negative {
+      f.write_str("-")?;
+    }
+    let digits: String =
+      self.digits.iter().map(|d| char::from(b'0' + d)).collect();
+    let len = digits.len() as i64;
+    // Position of the decimal point relative to the first digit. Can't
+    // overflow: `Decimal::new` guarantees it fits.
+    let point = len + self.exp;
+
+    if (0..=20).contains(&self.exp) {
+      write!(f, "{}{}", digits, "0".repeat(self.exp as usize))
+    } else if self.exp < 0 && point > 0 {
+      let (int, frac) = digits.split_at(point as usize);
+      write!(f, "{}.{}", int, frac)
+    } else if self.exp < 0 && point > -6 {
+      write!(f, "0.{}{}", "0".repeat(-point as usize), digits)
+    } else {
+      let (first, rest) = digits.split_at(1);
+      let rest = if rest.is_empty() {
+        String::new()
+      } else {
+        format!(".{}", rest)
+      };
+      write!(f, "{}{}e{}", first, rest, point - 1)
+    }
+  }
+}
+
+impl PartialOrd for Decimal {
+  fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Decimal {
+  fn cmp(&self, other: &Decimal) -> Ordering {
+    match (self.negative, other.negative) {
+      (false, true) => Ordering::Greater,
+      (true, false) => Ordering::Less,
+      (false, false) => self.cmp_abs(other),
+      (true, true) => other.cmp_abs(self),
+    }
+  }
+}
+
+impl Neg for Decimal {
+  type Output = Decimal;
+
+  fn neg(mut self) -> Decimal {
+    if !self.is_zero() {
+      self.negative = !self.negative;
+    }
+    self
+  }
+}
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | Unsigned integer helpers on decimal digits (most significant first).
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+fn trim_leading_zeros(v: &mut Vec<u8>) {
+  let leading = v.iter().take_while(|&&d| d == 0).count();
+  v.drain(..leading);
+}
+
+/// Compares integers without leading zeros.
+fn cmp_int(a: &[u8], b: &[u8]) -> Ordering {
+  a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn add_int(a: &[u8], b: &[u8]) -> Vec<u8> {
+  let mut out = Vec::with_capacity(a.len().max(b.len()) + 1);
+  let (mut i, mut j, mut carry) = (a.len(), b.len(), 0);
+  while i > 0 || j > 0 || carry > 0 {
+    let mut sum = carry;
+    if i > 0 {
+      i -= 1;
+      sum += a[i];
+    }
+    if j > 0 {
+      j -= 1;
+      sum += b[j];
+    }
+    out.push(sum % 10);
+    carry = sum / 10;
+  }
+  out.reverse();
+  out
+}
+
+/// Computes `a - b`, where `a >= b`.
+fn sub_int(a: &[u8], b: &[u8]) -> Vec<u8> {
+  let mut out = Vec::with_capacity(a.len());
+  let (mut j, mut borrow) = (b.len(), 0);
+  for &d in a.iter().rev() {
+    let mut sub = borrow;
+    if j > 0 {
+      j -= 1;
+      sub += b[j];
+    }
+    if d >= sub {
+      out.push(d - sub);
+      borrow = 0;
+    } else {
+      out.push(d + 10 - sub);
+      borrow = 1;
+    }
+  }
+  out.reverse();
+  trim_leading_zeros(&mut out);
+  out
+}
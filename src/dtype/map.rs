@@ -15,25 +15,42 @@
 //! A map of String to `sage::DType`.
 //!
 //! By default the map is backed by a [`BTreeMap`]. Enable the
-//! `preserve_order` feature of sage to use [`IndexMap`] instead.
+//! `preserve_order` feature of sage to use [`IndexMap`] instead, or the
+//! `fast_map` feature to use a [`HashMap`] when neither insertion order nor
+//! sorted keys matter (e.g. bulk ingest). The two features are mutually
+//! exclusive.
+//!
+//! With `fast_map` the iteration order is unspecified and the map iterators
+//! are not `DoubleEndedIterator`.
 //!
 //! [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
 //! [`IndexMap`]: https://docs.rs/indexmap/*/indexmap/map/struct.IndexMap.html
+//! [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
 
-use super::DType;
+use super::{DType, Number};
+use crate::error::ErrorCode;
 use serde::de;
 use std::{
   borrow::Borrow,
+  cmp::Ordering,
+  collections::HashMap,
   fmt,
   hash::Hash,
   iter::{FromIterator, FusedIterator},
   ops,
 };
 
+#[cfg(all(feature = "preserve_order", feature = "fast_map"))]
+compile_error!(
+  "features `preserve_order` and `fast_map` are mutually exclusive, enable at most one of them"
+);
+
 #[cfg(feature = "preserve_order")]
 use indexmap::{self, IndexMap};
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 use std::collections::btree_map::{self, BTreeMap};
+#[cfg(feature = "fast_map")]
+use std::collections::hash_map;
 
 /*
  * +----------------------------------------------------------------------+
@@ -47,10 +64,12 @@ use std::collections::btree_map::{self, BTreeMap};
 pub struct Map<K, V> {
   map: MapImpl<K, V>,
 }
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 type MapImpl<K, V> = BTreeMap<K, V>;
 #[cfg(feature = "preserve_order")]
 type MapImpl<K, V> = IndexMap<K, V>;
+#[cfg(feature = "fast_map")]
+type MapImpl<K, V> = HashMap<K, V>;
 
 impl Map<String, DType> {
   /// Makes a new empty Map.
@@ -65,7 +84,7 @@ impl Map<String, DType> {
   #[inline]
   pub fn with_capacity(capacity: usize) -> Self {
     Map {
-      #[cfg(not(feature = "preserve_order"))]
+      #[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
       map: {
         // does not support with_capacity
         let _ = capacity;
@@ -73,6 +92,8 @@ impl Map<String, DType> {
       },
       #[cfg(feature = "preserve_order")]
       map: IndexMap::with_capacity(capacity),
+      #[cfg(feature = "fast_map")]
+      map: HashMap::with_capacity(capacity),
     }
   }
 
@@ -142,10 +163,12 @@ impl Map<String, DType> {
     String: Borrow<Q>,
     Q: ?Sized + Ord + Eq + Hash,
   {
-    #[cfg(not(feature = "preserve_order"))]
+    #[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
     return self.map.remove(key);
     #[cfg(feature = "preserve_order")]
     return self.map.swap_remove(key);
+    #[cfg(feature = "fast_map")]
+    return self.map.remove(key);
   }
 
   /// Removes a key from the map, returning the stored key and value if the
@@ -158,10 +181,15 @@ impl Map<String, DType> {
     String: Borrow<Q>,
     Q: ?Sized + Ord + Eq + Hash,
   {
-    #[cfg(any(feature = "preserve_order", not(no_btreemap_remove_entry)))]
+    #[cfg(any(
+      feature = "preserve_order",
+      feature = "fast_map",
+      not(no_btreemap_remove_entry)
+    ))]
     return self.map.remove_entry(key);
     #[cfg(all(
       not(feature = "preserve_order"),
+      not(feature = "fast_map"),
       no_btreemap_remove_entry,
       not(no_btreemap_get_key_value),
     ))]
@@ -173,6 +201,7 @@ impl Map<String, DType> {
     }
     #[cfg(all(
       not(feature = "preserve_order"),
+      not(feature = "fast_map"),
       no_btreemap_remove_entry,
       no_btreemap_get_key_value,
     ))]
@@ -203,7 +232,9 @@ impl Map<String, DType> {
     for (k, v) in std::mem::take(&mut other.map) {
       self.map.insert(k, v);
     }
-    #[cfg(not(feature = "preserve_order"))]
+    #[cfg(feature = "fast_map")]
+    self.map.extend(other.map.drain());
+    #[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
     self.map.append(&mut other.map);
   }
 
@@ -215,8 +246,10 @@ impl Map<String, DType> {
   {
     #[cfg(feature = "preserve_order")]
     use indexmap::map::Entry as EntryImpl;
-    #[cfg(not(feature = "preserve_order"))]
+    #[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
     use std::collections::btree_map::Entry as EntryImpl;
+    #[cfg(feature = "fast_map")]
+    use std::collections::hash_map::Entry as EntryImpl;
 
     match self.map.entry(key.into()) {
       EntryImpl::Vacant(vacant) => Entry::Vacant(VacantEntry { vacant }),
@@ -226,6 +259,132 @@ impl Map<String, DType> {
     }
   }
 
+  /// Returns a mutable reference to the value at `key`, inserting `default`
+  /// first if the key is absent. Shorthand for
+  /// `map.entry(key).or_insert(default.into())`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{json, Map};
+  ///
+  /// let mut counts = Map::new();
+  /// for word in ["a", "b", "a"] {
+  ///   let n = counts.get_or_insert_default(word, 0);
+  ///   *n = json!(n.as_i64().unwrap() + 1);
+  /// }
+  /// assert_eq!(counts["a"], json!(2));
+  /// assert_eq!(counts["b"], json!(1));
+  /// ```
+  pub fn get_or_insert_default<T>(
+    &mut self,
+    key: &str,
+    default: T,
+  ) -> &mut DType
+  where
+    T: Into<DType>,
+  {
+    self.entry(key).or_insert(default.into())
+  }
+
+  /// Returns a mutable reference to the value at `key`, inserting the result
+  /// of `f` first if the key is absent. Shorthand for
+  /// `map.entry(key).or_insert_with(f)`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{json, DType, Map};
+  ///
+  /// let mut groups = Map::new();
+  /// for (group, item) in [("x", 1), ("y", 2), ("x", 3)] {
+  ///   if let DType::Array(items) =
+  ///     groups.get_or_insert_with(group, || DType::Array(vec![]))
+  ///   {
+  ///     items.push(json!(item));
+  ///   }
+  /// }
+  /// assert_eq!(groups["x"], json!([1, 3]));
+  /// assert_eq!(groups["y"], json!([2]));
+  /// ```
+  pub fn get_or_insert_with<F>(&mut self, key: &str, f: F) -> &mut DType
+  where
+    F: FnOnce() -> DType,
+  {
+    self.entry(key).or_insert_with(f)
+  }
+
+  /// Deserializes the value at `key` into a `T`, without going through
+  /// JSON text.
+  ///
+  /// Fails if `key` is missing or its value doesn't match `T`; the error's
+  /// [`path`](crate::Error::path) names the key.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use serde::Deserialize;
+  /// use sage::{json, Map};
+  ///
+  /// #[derive(Debug, Deserialize, PartialEq)]
+  /// struct Director {
+  ///   name: String,
+  ///   born: u16,
+  /// }
+  ///
+  /// let movie = json!({
+  ///   "name": "Avatar",
+  ///   "director": { "name": "James Cameron", "born": 1954 }
+  /// });
+  /// let movie = movie.as_object().unwrap();
+  ///
+  /// let director: Director = movie.get_typed("director").unwrap();
+  /// assert_eq!(director.born, 1954);
+  ///
+  /// assert!(movie.get_typed::<Director>("name").is_err());
+  /// assert!(movie.get_typed::<Director>("writer").is_err());
+  /// ```
+  pub fn get_typed<T>(&self, key: &str) -> crate::Result<T>
+  where
+    T: de::DeserializeOwned,
+  {
+    let value = self.get(key).ok_or_else(|| {
+      sage_err!(ErrorCode::Message(
+        format!("missing key {:?}", key).into_boxed_str()
+      ))
+    })?;
+    T::deserialize(value).map_err(|e| e.prepend_path(key))
+  }
+
+  /// Serializes `value` into a `DType` and inserts it at `key`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use serde::Serialize;
+  /// use sage::{json, Map};
+  ///
+  /// #[derive(Serialize)]
+  /// struct Director {
+  ///   name: String,
+  ///   born: u16,
+  /// }
+  ///
+  /// let mut movie = Map::new();
+  /// let director = Director { name: "James Cameron".to_string(), born: 1954 };
+  /// movie.insert_typed("director".to_string(), director).unwrap();
+  ///
+  /// assert_eq!(movie["director"], json!({ "name": "James Cameron", "born": 1954 }));
+  /// ```
+  pub fn insert_typed<T>(&mut self, key: String, value: T) -> crate::Result<()>
+  where
+    T: serde::Serialize,
+  {
+    let value = crate::to_dtype(value).map_err(|e| e.prepend_path(&key))?;
+    self.insert(key, value);
+    Ok(())
+  }
+
   /// Returns the number of elements in the map.
   #[inline]
   pub fn len(&self) -> usize {
@@ -277,6 +436,238 @@ impl Map<String, DType> {
       iter: self.map.values_mut(),
     }
   }
+
+  /// Clones the entries of the map into a `Vec` of key-value pairs, in the
+  /// map's iteration order.
+  pub fn to_vec(&self) -> Vec<(String, DType)> {
+    self.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+  }
+
+  /// Clones the entries of the map into a `Vec` of key-value pairs sorted by
+  /// key, regardless of the map's backing representation.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{json, Map};
+  ///
+  /// let map: Map<String, _> = Map::from_vec(vec![
+  ///   ("b".to_string(), json!(2)),
+  ///   ("a".to_string(), json!(1)),
+  /// ]);
+  /// assert_eq!(
+  ///   map.to_vec_sorted(),
+  ///   vec![("a".to_string(), json!(1)), ("b".to_string(), json!(2))]
+  /// );
+  /// ```
+  pub fn to_vec_sorted(&self) -> Vec<(String, DType)> {
+    let mut v = self.to_vec();
+    v.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    v
+  }
+
+  /// Borrows the entries of the map as a `HashMap`, for interoperating with
+  /// code which expects one. Only the references are collected; keys and
+  /// values are not cloned.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{json, Map};
+  ///
+  /// let mut map = Map::new();
+  /// map.insert("name".to_string(), json!("Sage"));
+  ///
+  /// let hm = map.as_hashmap();
+  /// assert_eq!(hm["name"], &json!("Sage"));
+  /// ```
+  pub fn as_hashmap(&self) -> HashMap<&str, &DType> {
+    self.iter().map(|(k, v)| (k.as_str(), v)).collect()
+  }
+
+  /// Converts the map into a `HashMap`. Free with the `fast_map` feature,
+  /// which is already backed by one.
+  #[inline]
+  pub fn into_hashmap(self) -> HashMap<String, DType> {
+    #[cfg(feature = "fast_map")]
+    {
+      self.map
+    }
+    #[cfg(not(feature = "fast_map"))]
+    self.map.into_iter().collect()
+  }
+
+  /// Builds a map from a `HashMap`. With `preserve_order` the entries are
+  /// kept in the `HashMap`'s (unspecified) iteration order.
+  #[inline]
+  pub fn from_hashmap(m: HashMap<String, DType>) -> Self {
+    #[cfg(feature = "fast_map")]
+    {
+      Map { map: m }
+    }
+    #[cfg(not(feature = "fast_map"))]
+    m.into_iter().collect()
+  }
+
+  /// Borrows the `BTreeMap` backing the map. Only available when neither
+  /// `preserve_order` nor `fast_map` is enabled.
+  #[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
+  #[inline]
+  pub fn as_btreemap(&self) -> &BTreeMap<String, DType> {
+    &self.map
+  }
+
+  /// Borrows the `IndexMap` backing the map. Only available with the
+  /// `preserve_order` feature.
+  #[cfg(feature = "preserve_order")]
+  #[inline]
+  pub fn as_indexmap(&self) -> &IndexMap<String, DType> {
+    &self.map
+  }
+
+  /// Returns the entries of the map sorted with the comparator `cmp`, which
+  /// receives the key & value of both entries being compared. The sort is
+  /// stable.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{json, DType};
+  ///
+  /// let scores = json!({ "a": 3, "b": 1, "c": 2 });
+  /// let map = scores.as_object().unwrap();
+  ///
+  /// let sorted = map.sort_values_by(|_, a, _, b| {
+  ///   a.as_i64().cmp(&b.as_i64()).reverse()
+  /// });
+  /// let keys: Vec<_> = sorted.iter().map(|(k, _)| k.as_str()).collect();
+  /// assert_eq!(keys, vec!["a", "c", "b"]);
+  /// ```
+  pub fn sort_values_by<F>(&self, mut cmp: F) -> Vec<(&String, &DType)>
+  where
+    F: FnMut(&str, &DType, &str, &DType) -> Ordering,
+  {
+    let mut entries: Vec<_> = self.iter().collect();
+    entries.sort_by(|(ka, va), (kb, vb)| cmp(ka, va, kb, vb));
+    entries
+  }
+
+  /// Returns the entries of the map sorted in ascending order of the `field`
+  /// of their (object) values, e.g. ranking entities by a `"score"`.
+  ///
+  /// Numbers compare numerically (integers exactly, even beyond 2^53),
+  /// strings lexically, booleans & datetimes in their natural order. Values
+  /// of different types are grouped by type. Entries without the field (or
+  /// with a `null` one) always come last. Ties keep the map's iteration
+  /// order, except with `fast_map` where they are broken by key.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::json;
+  ///
+  /// let people = json!({
+  ///   "ada": { "citations": 120 },
+  ///   "alan": { "citations": 75.5 },
+  ///   "grace": { "citations": 300 },
+  ///   "anon": {},
+  /// });
+  /// let map = people.as_object().unwrap();
+  ///
+  /// let asc: Vec<_> = map.sort_values_by_key_asc("citations").into_iter().map(|(k, _)| k.as_str()).collect();
+  /// assert_eq!(asc, vec!["alan", "ada", "grace", "anon"]);
+  ///
+  /// let desc: Vec<_> = map.sort_values_by_key_desc("citations").into_iter().map(|(k, _)| k.as_str()).collect();
+  /// assert_eq!(desc, vec!["grace", "ada", "alan", "anon"]);
+  ///
+  /// // Integers beyond 2^53 don't collapse through `f64`.
+  /// let ids = json!({ "a": { "n": 9007199254740993u64 }, "b": { "n": 9007199254740992u64 } });
+  /// let asc: Vec<_> = ids.as_object().unwrap().sort_values_by_key_asc("n").into_iter().map(|(k, _)| k.as_str()).collect();
+  /// assert_eq!(asc, vec!["b", "a"]);
+  /// ```
+  pub fn sort_values_by_key_asc(&self, field: &str) -> Vec<(&String, &DType)> {
+    self.sort_values_by(|ka, a, kb, b| {
+      cmp_field(a, b, field, false).then_with(|| cmp_tie(ka, kb))
+    })
+  }
+
+  /// Like [`Map::sort_values_by_key_asc`] but in descending order. Entries
+  /// without the field still come last.
+  pub fn sort_values_by_key_desc(&self, field: &str) -> Vec<(&String, &DType)> {
+    self.sort_values_by(|ka, a, kb, b| {
+      cmp_field(a, b, field, true).then_with(|| cmp_tie(ka, kb))
+    })
+  }
+
+  /// Builds a map from a `Vec` of key-value pairs. With `preserve_order`
+  /// the map keeps the order of the `Vec`. When a key is repeated the last
+  /// value wins.
+  #[inline]
+  pub fn from_vec(v: Vec<(String, DType)>) -> Self {
+    v.into_iter().collect()
+  }
+}
+
+/// Compares the `field` of two object values, keeping missing & `null`
+/// fields last regardless of `reverse`.
+fn cmp_field(a: &DType, b: &DType, field: &str, reverse: bool) -> Ordering {
+  let (a, b) = (a.get(field), b.get(field));
+  let (a, b) = match (a.filter(|v| !v.is_null()), b.filter(|v| !v.is_null())) {
+    (None, None) => return Ordering::Equal,
+    (None, Some(_)) => return Ordering::Greater,
+    (Some(_), None) => return Ordering::Less,
+    (Some(a), Some(b)) => (a, b),
+  };
+  let ord = match (a, b) {
+    (DType::Number(x), DType::Number(y)) => cmp_numbers(x, y),
+    (DType::String(x), DType::String(y)) => x.cmp(y),
+    (DType::Boolean(x), DType::Boolean(y)) => x.cmp(y),
+    (DType::DateTime(x), DType::DateTime(y)) => x.cmp(y),
+    _ => type_rank(a).cmp(&type_rank(b)),
+  };
+  if reverse {
+    ord.reverse()
+  } else {
+    ord
+  }
+}
+
+/// Compares two numbers exactly when both are integers, falling back to
+/// `f64` only when a float is involved.
+fn cmp_numbers(x: &Number, y: &Number) -> Ordering {
+  match (x.as_u64(), y.as_u64(), x.as_i64(), y.as_i64()) {
+    (Some(x), Some(y), _, _) => x.cmp(&y),
+    (_, _, Some(x), Some(y)) => x.cmp(&y),
+    // A `u64` beyond `i64::MAX` against a negative integer.
+    (Some(_), None, _, Some(_)) => Ordering::Greater,
+    (None, Some(_), Some(_), _) => Ordering::Less,
+    _ => x
+      .as_f64()
+      .partial_cmp(&y.as_f64())
+      .unwrap_or(Ordering::Equal),
+  }
+}
+
+/// Breaks ties between equal entries. Other backends already iterate in a
+/// stable order, but a `HashMap`'s is arbitrary so fall back to the key.
+fn cmp_tie(ka: &str, kb: &str) -> Ordering {
+  if cfg!(feature = "fast_map") {
+    ka.cmp(kb)
+  } else {
+    Ordering::Equal
+  }
+}
+
+fn type_rank(value: &DType) -> u8 {
+  match value {
+    DType::Boolean(_) => 0,
+    DType::Number(_) => 1,
+    DType::String(_) => 2,
+    DType::DateTime(_) => 3,
+    DType::Array(_) => 4,
+    DType::Object(_) => 5,
+    DType::Null => 6,
+  }
 }
 
 /*
@@ -314,6 +705,28 @@ impl PartialEq for Map<String, DType> {
 
 impl Eq for Map<String, DType> {}
 
+/// Hashes the entries in sorted key order, so equal maps hash alike
+/// whatever their backing representation or insertion order.
+#[cfg(feature = "hashable-map")]
+impl Hash for Map<String, DType> {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    #[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
+    let entries: Vec<(&String, &DType)> = self.map.iter().collect();
+    #[cfg(any(feature = "preserve_order", feature = "fast_map"))]
+    let entries: Vec<(&String, &DType)> = {
+      let mut entries: Vec<_> = self.map.iter().collect();
+      entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+      entries
+    };
+
+    state.write_usize(entries.len());
+    for (k, v) in entries {
+      k.hash(state);
+      v.hash(state);
+    }
+  }
+}
+
 /// Access an element of this map. Panics if the given key is not present in the
 /// map.
 ///
@@ -454,6 +867,13 @@ impl FromIterator<(String, DType)> for Map<String, DType> {
   }
 }
 
+impl From<Vec<(String, DType)>> for Map<String, DType> {
+  #[inline]
+  fn from(v: Vec<(String, DType)>) -> Self {
+    Map::from_vec(v)
+  }
+}
+
 impl Extend<(String, DType)> for Map<String, DType> {
   fn extend<T>(&mut self, iter: T)
   where
@@ -481,6 +901,7 @@ macro_rules! delegate_iterator {
             }
         }
 
+        #[cfg(not(feature = "fast_map"))]
         impl $($generics)* DoubleEndedIterator for $name $($generics)* {
             #[inline]
             fn next_back(&mut self) -> Option<Self::Item> {
@@ -533,15 +954,19 @@ pub struct OccupiedEntry<'a> {
   occupied: OccupiedEntryImpl<'a>,
 }
 
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 type VacantEntryImpl<'a> = btree_map::VacantEntry<'a, String, DType>;
 #[cfg(feature = "preserve_order")]
 type VacantEntryImpl<'a> = indexmap::map::VacantEntry<'a, String, DType>;
+#[cfg(feature = "fast_map")]
+type VacantEntryImpl<'a> = hash_map::VacantEntry<'a, String, DType>;
 
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 type OccupiedEntryImpl<'a> = btree_map::OccupiedEntry<'a, String, DType>;
 #[cfg(feature = "preserve_order")]
 type OccupiedEntryImpl<'a> = indexmap::map::OccupiedEntry<'a, String, DType>;
+#[cfg(feature = "fast_map")]
+type OccupiedEntryImpl<'a> = hash_map::OccupiedEntry<'a, String, DType>;
 
 /*
  * +----------------------------------------------------------------------+
@@ -611,6 +1036,28 @@ impl<'a> Entry<'a> {
     }
   }
 
+  /// Ensures a value is in the entry by inserting `DType::default()` (which
+  /// is `Null`) if empty, and returns a mutable reference to the value in the
+  /// entry.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use sage::json;
+  /// #
+  /// let mut counts = sage::Map::new();
+  /// for word in ["a", "b", "a"] {
+  ///     let n = counts.entry(word).or_default();
+  ///     *n = json!(n.as_u64().unwrap_or(0) + 1);
+  /// }
+  ///
+  /// assert_eq!(counts["a"], 2);
+  /// assert_eq!(counts["b"], 1);
+  /// ```
+  pub fn or_default(self) -> &'a mut DType {
+    self.or_insert_with(DType::default)
+  }
+
   /// Provides in-place mutable access to an occupied entry before any
   /// potential inserts into the map.
   ///
@@ -863,6 +1310,34 @@ impl<'a> OccupiedEntry<'a> {
     #[cfg(not(feature = "preserve_order"))]
     return self.occupied.remove();
   }
+
+  /// Takes the key & value of the entry out of the map, and returns them.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// use sage::map::Entry;
+  ///
+  /// let mut map = sage::Map::new();
+  /// map.insert("sage".to_owned(), json!(12));
+  ///
+  /// match map.entry("sage") {
+  ///     Entry::Occupied(occupied) => {
+  ///         assert_eq!(occupied.remove_entry(), ("sage".to_owned(), json!(12)));
+  ///     }
+  ///     Entry::Vacant(_) => unimplemented!(),
+  /// }
+  /// assert!(map.is_empty());
+  /// ```
+  #[inline]
+  pub fn remove_entry(self) -> (String, DType) {
+    #[cfg(feature = "preserve_order")]
+    return self.occupied.swap_remove_entry();
+    #[cfg(not(feature = "preserve_order"))]
+    return self.occupied.remove_entry();
+  }
 }
 
 impl<'a> IntoIterator for &'a Map<String, DType> {
@@ -890,10 +1365,12 @@ pub struct Iter<'a> {
   iter: IterImpl<'a>,
 }
 
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 type IterImpl<'a> = btree_map::Iter<'a, String, DType>;
 #[cfg(feature = "preserve_order")]
 type IterImpl<'a> = indexmap::map::Iter<'a, String, DType>;
+#[cfg(feature = "fast_map")]
+type IterImpl<'a> = hash_map::Iter<'a, String, DType>;
 
 delegate_iterator!((Iter<'a>) => (&'a String, &'a DType));
 
@@ -913,10 +1390,12 @@ pub struct IterMut<'a> {
   iter: IterMutImpl<'a>,
 }
 
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 type IterMutImpl<'a> = btree_map::IterMut<'a, String, DType>;
 #[cfg(feature = "preserve_order")]
 type IterMutImpl<'a> = indexmap::map::IterMut<'a, String, DType>;
+#[cfg(feature = "fast_map")]
+type IterMutImpl<'a> = hash_map::IterMut<'a, String, DType>;
 
 delegate_iterator!((IterMut<'a>) => (&'a String, &'a mut DType));
 
@@ -936,10 +1415,12 @@ pub struct IntoIter {
   iter: IntoIterImpl,
 }
 
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 type IntoIterImpl = btree_map::IntoIter<String, DType>;
 #[cfg(feature = "preserve_order")]
 type IntoIterImpl = indexmap::map::IntoIter<String, DType>;
+#[cfg(feature = "fast_map")]
+type IntoIterImpl = hash_map::IntoIter<String, DType>;
 
 delegate_iterator!((IntoIter) => (String, DType));
 
@@ -955,10 +1436,12 @@ pub struct Keys<'a> {
   iter: KeysImpl<'a>,
 }
 
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 type KeysImpl<'a> = btree_map::Keys<'a, String, DType>;
 #[cfg(feature = "preserve_order")]
 type KeysImpl<'a> = indexmap::map::Keys<'a, String, DType>;
+#[cfg(feature = "fast_map")]
+type KeysImpl<'a> = hash_map::Keys<'a, String, DType>;
 
 delegate_iterator!((Keys<'a>) => &'a String);
 
@@ -975,10 +1458,12 @@ pub struct Values<'a> {
   iter: ValuesImpl<'a>,
 }
 
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 type ValuesImpl<'a> = btree_map::Values<'a, String, DType>;
 #[cfg(feature = "preserve_order")]
 type ValuesImpl<'a> = indexmap::map::Values<'a, String, DType>;
+#[cfg(feature = "fast_map")]
+type ValuesImpl<'a> = hash_map::Values<'a, String, DType>;
 
 delegate_iterator!((Values<'a>) => &'a DType);
 
@@ -987,9 +1472,11 @@ pub struct ValuesMut<'a> {
   iter: ValuesMutImpl<'a>,
 }
 
-#[cfg(not(feature = "preserve_order"))]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
 type ValuesMutImpl<'a> = btree_map::ValuesMut<'a, String, DType>;
 #[cfg(feature = "preserve_order")]
 type ValuesMutImpl<'a> = indexmap::map::ValuesMut<'a, String, DType>;
+#[cfg(feature = "fast_map")]
+type ValuesMutImpl<'a> = hash_map::ValuesMut<'a, String, DType>;
 
 delegate_iterator!((ValuesMut<'a>) => &'a mut DType);
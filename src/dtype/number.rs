@@ -14,7 +14,10 @@
 
 #![allow(dead_code)]
 
-use std::fmt;
+use std::{
+  fmt,
+  hash::{Hash, Hasher},
+};
 
 use serde::{
   de::{self, Unexpected, Visitor},
@@ -22,6 +25,8 @@ use serde::{
   Serialize,
 };
 
+#[cfg(feature = "arbitrary_precision")]
+use crate::dtype::decimal::Decimal;
 #[cfg(feature = "arbitrary_precision")]
 use crate::error::ErrorCode;
 use crate::{Error, Result};
@@ -29,6 +34,11 @@ use crate::{Error, Result};
 use serde::de::{IntoDeserializer, MapAccess};
 #[cfg(feature = "arbitrary_precision")]
 pub(crate) const TOKEN: &str = "$sage::dtype::Number";
+/// The private struct name `serde_json::Number` serializes through when its
+/// own "arbitrary_precision" feature is on (which ours forwards to). It is
+/// treated exactly like [`TOKEN`] so `serde_json::Value`s pass through intact.
+#[cfg(feature = "arbitrary_precision")]
+pub(crate) const SERDE_JSON_TOKEN: &str = "$serde_json::private::Number";
 
 /*
  * +----------------------------------------------------------------------+
@@ -39,7 +49,11 @@ pub(crate) const TOKEN: &str = "$sage::dtype::Number";
 */
 
 /// Represents a number, whether integer or floating point.
-#[derive(Clone, Eq, PartialEq)]
+///
+/// With the `arbitrary_precision` feature, numbers are compared by value
+/// rather than by their text, so `1e2`, `100` & `100.0` are all equal.
+#[derive(Clone, Eq)]
+#[cfg_attr(not(feature = "arbitrary_precision"), derive(PartialEq))]
 pub struct Number {
   /// Number enum implementation.
   pub(crate) n: NumImpl,
@@ -274,6 +288,103 @@ impl Number {
   }
 }
 
+#[cfg(feature = "arbitrary_precision")]
+impl Number {
+  /// Parses the `Number` into an exact [`Decimal`]. Returns `None` for
+  /// numbers whose exponent is out of range (e.g. `10e9223372036854775807`)
+  /// and for numbers built from invalid text with `from_string_unchecked`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # #[cfg(feature = "arbitrary_precision")]
+  /// # {
+  /// use sage::{json, Number};
+  ///
+  /// // A 40-digit integer survives `to_dtype` & `from_dtype` untouched.
+  /// let text = "1234567890123456789012345678901234567890";
+  /// let value: sage::DType = json::from_str(text).unwrap();
+  /// let value: sage::DType = sage::from_dtype(sage::to_dtype(&value).unwrap()).unwrap();
+  /// assert_eq!(json::to_string(&value).unwrap(), text);
+  ///
+  /// // ...and arithmetic on it is exact.
+  /// let n = match &value {
+  ///   sage::DType::Number(n) => n,
+  ///   _ => unreachable!(),
+  /// };
+  /// let sum = n.checked_add(&Number::from(10u64)).unwrap();
+  /// assert_eq!(sum.to_string(), "1234567890123456789012345678901234567900");
+  ///
+  /// // Comparisons work across representations.
+  /// assert_eq!(Number::from_string_unchecked("1e2".to_string()), Number::from(100u64));
+  ///
+  /// // Out of range exponents compare by text instead of panicking.
+  /// let huge: sage::DType = json::from_str("10e9223372036854775807").unwrap();
+  /// assert_eq!(huge, huge.clone());
+  /// assert_ne!(huge, sage::DType::from(1));
+  /// # }
+  /// ```
+  ///
+  /// [`Decimal`]: crate::dtype::decimal::Decimal
+  pub fn as_decimal(&self) -> Option<Decimal> {
+    self.n.parse().ok()
+  }
+
+  /// Divides `self` by `other` exactly. Returns `None` if `other` is zero,
+  /// the quotient has no finite decimal expansion or either number has no
+  /// [`Decimal`] value.
+  ///
+  /// [`Decimal`]: crate::dtype::decimal::Decimal
+  pub fn div_exact(&self, other: &Number) -> Option<Number> {
+    self
+      .as_decimal()?
+      .div_exact(&other.as_decimal()?)
+      .map(Number::from)
+  }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl PartialEq for Number {
+  fn eq(&self, other: &Number) -> bool {
+    self.n == other.n
+      || match (self.as_decimal(), other.as_decimal()) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+      }
+  }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl From<Decimal> for Number {
+  fn from(d: Decimal) -> Self {
+    Number { n: d.to_string() }
+  }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+macro_rules! impl_decimal_op {
+  ($($method:ident),*) => {
+    impl Number {
+      $(
+        /// Exact arithmetic on the decimal value of the numbers. Returns
+        /// `None` if either number has no `Decimal` value or the result is
+        /// out of range (see [`Decimal`]).
+        ///
+        /// [`Decimal`]: crate::dtype::decimal::Decimal
+        pub fn $method(&self, other: &Number) -> Option<Number> {
+          self
+            .as_decimal()?
+            .$method(&other.as_decimal()?)
+            .map(Number::from)
+        }
+      )*
+    }
+  };
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl_decimal_op!(checked_add, checked_sub, checked_mul);
+
 impl fmt::Display for Number {
   #[cfg(not(feature = "arbitrary_precision"))]
   fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -311,6 +422,32 @@ impl fmt::Debug for Number {
   }
 }
 
+// `Hash` must agree with `PartialEq`.
+impl Hash for Number {
+  #[cfg(not(feature = "arbitrary_precision"))]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    match self.n {
+      NumImpl::PositiveInt(u) => (0u8, u).hash(state),
+      NumImpl::NegativeInt(i) => (1u8, i).hash(state),
+      NumImpl::Float(f) => {
+        // `0.0 == -0.0`, so both must hash alike.
+        let f = if f == 0.0 { 0.0 } else { f };
+        (2u8, f.to_bits()).hash(state)
+      }
+    }
+  }
+
+  /// Numbers equal by value (`1e2` & `100`) hash by their normalized
+  /// decimal.
+  #[cfg(feature = "arbitrary_precision")]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    match self.as_decimal() {
+      Some(d) => d.hash(state),
+      None => self.n.hash(state),
+    }
+  }
+}
+
 /*
  * +----------------------------------------------------------------------+
  * | +------------------------------------------------------------------+ |
@@ -438,7 +575,7 @@ impl<'de> de::Deserialize<'de> for NumberKey {
       where
         E: de::Error,
       {
-        if s == TOKEN {
+        if s == TOKEN || s == SERDE_JSON_TOKEN {
           Ok(())
         } else {
           Err(de::Error::custom("expected field with custom name"))
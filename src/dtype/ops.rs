@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod csv;
 mod de;
 mod from;
 mod index;
@@ -22,7 +23,7 @@ mod ser;
 
 pub use ser::{
   SerializeMap, SerializeStructVariant, SerializeTupleVariant, SerializeVec,
-  Serializer,
+  Serializer, StrictSerializer,
 };
 
 pub use index::Index;
@@ -0,0 +1,139 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CSV row conversion for `DType`, following the quoting & escaping rules of
+//! [RFC 4180].
+//!
+//! [RFC 4180]: https://tools.ietf.org/html/rfc4180
+
+use crate::{datastore::json, dtype::DType, Map};
+
+impl DType {
+  /// Serializes the `DType` as a single CSV row (without a line break).
+  ///
+  /// - `Object` entries become `key=value` fields.
+  /// - `Array` elements become one field each.
+  /// - Scalars become a single field. `Null` is an empty field.
+  ///
+  /// Nested arrays & objects are written as compact JSON. Fields containing a
+  /// comma, double quote or line break are quoted, with inner double quotes
+  /// doubled, as described in [RFC 4180].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::json;
+  /// #
+  /// assert_eq!(json!(["Sage", 42, null, "a, b"]).to_csv_row(), r#"Sage,42,,"a, b""#);
+  /// assert_eq!(json!({ "q": "say \"hi\"" }).to_csv_row(), r#""q=say ""hi""""#);
+  /// assert_eq!(json!([[1, 2]]).to_csv_row(), r#""[1,2]""#);
+  /// ```
+  ///
+  /// [RFC 4180]: https://tools.ietf.org/html/rfc4180
+  pub fn to_csv_row(&self) -> String {
+    let fields: Vec<String> = match self {
+      DType::Object(m) => m
+        .iter()
+        .map(|(k, v)| escape(&format!("{}={}", k, csv_field(v))))
+        .collect(),
+      DType::Array(v) => v.iter().map(|x| escape(&csv_field(x))).collect(),
+      scalar => vec![escape(&csv_field(scalar))],
+    };
+    fields.join(",")
+  }
+
+  /// Parses a single CSV row into a `DType::Object` keyed by `header`.
+  ///
+  /// Each field is typed with [`DType::from_str_auto`], except for empty
+  /// fields which become `Null`. Fields missing from the end of the row are
+  /// also `Null` and fields beyond the header are dropped. Quoted fields
+  /// follow [RFC 4180] (`""` inside quotes is a literal `"`).
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{json, DType};
+  ///
+  /// let row = DType::from_csv_row(&["name", "age", "bio", "email"], r#"Jane,42,"Likes ""Rust"", cats""#);
+  /// assert_eq!(
+  ///   row,
+  ///   json!({ "name": "Jane", "age": 42, "bio": "Likes \"Rust\", cats", "email": null })
+  /// );
+  /// ```
+  ///
+  /// [RFC 4180]: https://tools.ietf.org/html/rfc4180
+  pub fn from_csv_row(header: &[&str], row: &str) -> DType {
+    let mut fields = split_row(row).into_iter();
+    let map: Map<String, DType> = header
+      .iter()
+      .map(|key| {
+        let value = match fields.next() {
+          Some(ref f) if !f.is_empty() => DType::from_str_auto(f),
+          _ => DType::Null,
+        };
+        (key.to_string(), value)
+      })
+      .collect();
+    DType::Object(map)
+  }
+}
+
+/// Text of a single (unescaped) CSV field.
+fn csv_field(value: &DType) -> String {
+  match value {
+    DType::Null => String::new(),
+    DType::Boolean(b) => b.to_string(),
+    DType::Number(n) => n.to_string(),
+    DType::String(s) => s.clone(),
+    DType::DateTime(d) => d.to_string(),
+    DType::Array(_) | DType::Object(_) => {
+      json::to_string(value).unwrap_or_default()
+    }
+  }
+}
+
+/// Quotes `field` if it contains a comma, double quote or line break.
+fn escape(field: &str) -> String {
+  if field.contains([',', '"', '\r', '\n']) {
+    format!("\"{}\"", field.replace('"', "\"\""))
+  } else {
+    field.to_string()
+  }
+}
+
+/// Splits a CSV row into its unescaped fields.
+fn split_row(row: &str) -> Vec<String> {
+  let mut fields = Vec::new();
+  let mut field = String::new();
+  let mut in_quotes = false;
+  let mut chars = row.chars().peekable();
+
+  while let Some(c) = chars.next() {
+    match c {
+      '"' if in_quotes => {
+        if chars.peek() == Some(&'"') {
+          chars.next();
+          field.push('"');
+        } else {
+          in_quotes = false;
+        }
+      }
+      '"' if field.is_empty() => in_quotes = true,
+      ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+      c => field.push(c),
+    }
+  }
+  fields.push(field);
+  fields
+}
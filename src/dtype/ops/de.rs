@@ -127,10 +127,11 @@ impl<'de> Deserialize<'de> for DType {
             let number: NumberFromString = visitor.next_value()?;
             Ok(DType::Number(number.value))
           }
-          #[cfg(feature = "raw_value")]
+          #[cfg(feature = "raw_dtype")]
           Some(KeyClass::RawDType) => {
-            let value = visitor.next_value_seed(crate::raw::BoxedFromString)?;
-            crate::from_str(value.get()).map_err(de::Error::custom)
+            let value =
+              visitor.next_value_seed(crate::json::BoxedFromString)?;
+            crate::json::from_str(value.get()).map_err(de::Error::custom)
           }
           Some(KeyClass::Map(first_key)) => {
             let mut values = Map::new();
@@ -239,15 +240,15 @@ where
   }
 }
 
-// TODO: Implement this function for `visit_datetime`.
+/// Hands a `DateTime` to `visitor` as its RFC 3339 string.
 fn visit_datetime<'de, V>(
-  _datetime: DateTime,
-  _visitor: V,
+  datetime: DateTime,
+  visitor: V,
 ) -> Result<V::Value, Error>
 where
   V: Visitor<'de>,
 {
-  todo!()
+  visitor.visit_string(datetime.to_string())
 }
 
 /*
@@ -356,11 +357,11 @@ impl<'de> serde::Deserializer<'de> for DType {
   where
     V: Visitor<'de>,
   {
-    #[cfg(feature = "raw_value")]
+    #[cfg(feature = "raw_dtype")]
     {
-      if name == crate::raw::TOKEN {
-        return visitor.visit_map(crate::raw::OwnedRawDeserializer {
-          raw_value: Some(self.to_string()),
+      if name == crate::json::TOKEN {
+        return visitor.visit_map(crate::json::OwnedRawDeserializer {
+          raw_dtype: Some(self.to_string()),
         });
       }
     }
@@ -399,6 +400,7 @@ impl<'de> serde::Deserializer<'de> for DType {
   {
     match self {
       DType::String(v) => visitor.visit_string(v),
+      DType::DateTime(d) => visit_datetime(d, visitor),
       _ => Err(self.invalid_type(&visitor)),
     }
   }
@@ -815,15 +817,16 @@ where
   }
 }
 
-// TODO: Implement this function for datetime.
+/// Like `visit_datetime`, handing a borrowed `DateTime` over as its RFC 3339
+/// string.
 fn visit_datetime_ref<'de, V>(
-  _datetime: &'de DateTime,
-  _visitor: V,
+  datetime: &'de DateTime,
+  visitor: V,
 ) -> Result<V::Value, Error>
 where
   V: Visitor<'de>,
 {
-  todo!()
+  visitor.visit_string(datetime.to_string())
 }
 
 /*
@@ -929,11 +932,11 @@ impl<'de> serde::Deserializer<'de> for &'de DType {
   where
     V: Visitor<'de>,
   {
-    #[cfg(feature = "raw_value")]
+    #[cfg(feature = "raw_dtype")]
     {
-      if name == crate::raw::TOKEN {
-        return visitor.visit_map(crate::raw::OwnedRawDeserializer {
-          raw_value: Some(self.to_string()),
+      if name == crate::json::TOKEN {
+        return visitor.visit_map(crate::json::OwnedRawDeserializer {
+          raw_dtype: Some(self.to_string()),
         });
       }
     }
@@ -965,6 +968,7 @@ impl<'de> serde::Deserializer<'de> for &'de DType {
   {
     match *self {
       DType::String(ref v) => visitor.visit_borrowed_str(v),
+      DType::DateTime(ref d) => visit_datetime_ref(d, visitor),
       _ => Err(self.invalid_type(&visitor)),
     }
   }
@@ -1401,7 +1405,7 @@ enum KeyClass {
   Map(String),
   #[cfg(feature = "arbitrary_precision")]
   Number,
-  #[cfg(feature = "raw_value")]
+  #[cfg(feature = "raw_dtype")]
   RawDType,
 }
 
@@ -1429,9 +1433,11 @@ impl<'de> Visitor<'de> for KeyClassifier {
   {
     match s {
       #[cfg(feature = "arbitrary_precision")]
-      crate::number::TOKEN => Ok(KeyClass::Number),
-      #[cfg(feature = "raw_value")]
-      crate::raw::TOKEN => Ok(KeyClass::RawDType),
+      crate::number::TOKEN | crate::number::SERDE_JSON_TOKEN => {
+        Ok(KeyClass::Number)
+      }
+      #[cfg(feature = "raw_dtype")]
+      crate::json::TOKEN => Ok(KeyClass::RawDType),
       _ => Ok(KeyClass::Map(s.to_owned())),
     }
   }
@@ -1442,9 +1448,11 @@ impl<'de> Visitor<'de> for KeyClassifier {
   {
     match s.as_str() {
       #[cfg(feature = "arbitrary_precision")]
-      crate::number::TOKEN => Ok(KeyClass::Number),
-      #[cfg(feature = "raw_value")]
-      crate::raw::TOKEN => Ok(KeyClass::RawDType),
+      crate::number::TOKEN | crate::number::SERDE_JSON_TOKEN => {
+        Ok(KeyClass::Number)
+      }
+      #[cfg(feature = "raw_dtype")]
+      crate::json::TOKEN => Ok(KeyClass::RawDType),
       _ => Ok(KeyClass::Map(s)),
     }
   }
@@ -1460,7 +1468,7 @@ impl DType {
   }
 
   #[cold]
-  fn unexpected(&self) -> Unexpected {
+  pub(crate) fn unexpected(&self) -> Unexpected {
     match *self {
       DType::Null => Unexpected::Unit,
       DType::Boolean(b) => Unexpected::Bool(b),
@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::dtype::{map::Map, number::Number, DType};
+use crate::{
+  dtype::{datetime::DateTime, map::Map, number::Number, DType},
+  error::{Error, ErrorCode},
+};
 
-use std::{borrow::Cow, iter::FromIterator};
+use std::{borrow::Cow, convert::TryFrom, iter::FromIterator};
+
+use serde_json::Value;
 
 macro_rules! from_integer {
   ($($ty:ident)*) => {
@@ -154,6 +159,51 @@ impl From<Number> for DType {
   }
 }
 
+impl From<DateTime> for DType {
+  /// Convert `DateTime` to `DType`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use sage::{DateTime, DType};
+  ///
+  /// let d: DateTime = "2021-05-04".parse().unwrap();
+  /// let x: DType = d.into();
+  /// ```
+  fn from(f: DateTime) -> Self {
+    DType::DateTime(f)
+  }
+}
+
+impl From<chrono::DateTime<chrono::Utc>> for DType {
+  /// Convert `chrono::DateTime<Utc>` to `DType::DateTime`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use sage::DType;
+  ///
+  /// use chrono::{TimeZone, Utc};
+  ///
+  /// let x: DType = Utc.timestamp_opt(0, 0).unwrap().into();
+  /// assert!(matches!(x, DType::DateTime(_)));
+  /// ```
+  fn from(f: chrono::DateTime<chrono::Utc>) -> Self {
+    DType::DateTime(f.into())
+  }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for DType {
+  type Error = Error;
+
+  /// Convert `time::OffsetDateTime` to `DType::DateTime`.
+  /// See [`DateTime`] for the conversion rules.
+  fn try_from(f: time::OffsetDateTime) -> Result<Self, Self::Error> {
+    DateTime::try_from(f).map(DType::DateTime)
+  }
+}
+
 impl From<Map<String, DType>> for DType {
   /// Convert map (with string keys) to `Dtype`.
   ///
@@ -269,3 +319,190 @@ impl From<()> for DType {
     DType::Null
   }
 }
+
+impl From<Value> for DType {
+  /// Convert a `serde_json::Value` to `DType` without going through a
+  /// serialize/deserialize round-trip.
+  ///
+  /// Integers keep their representation: values above `i64::MAX` stay `u64`,
+  /// negative values stay `i64` and everything else becomes `f64`. Object
+  /// keys are inserted in the order `serde_json` yields them, so enabling
+  /// `preserve_order` keeps the input order.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::DType;
+  ///
+  /// let big = i64::MAX as u64 + 10;
+  /// let value = serde_json::json!({ "name": "sage", "big": big, "pi": 3.14 });
+  ///
+  /// let x: DType = value.into();
+  /// assert_eq!(x["name"], "sage");
+  /// assert_eq!(x["big"].as_u64(), Some(big));
+  /// assert_eq!(x["pi"].as_f64(), Some(3.14));
+  /// ```
+  fn from(value: Value) -> Self {
+    match value {
+      Value::Null => DType::Null,
+      Value::Bool(b) => DType::Boolean(b),
+      Value::Number(n) => {
+        number_from_json(&n).map_or(DType::Null, DType::Number)
+      }
+      Value::String(s) => DType::String(s),
+      Value::Array(a) => DType::Array(a.into_iter().map(Into::into).collect()),
+      Value::Object(o) => {
+        DType::Object(o.into_iter().map(|(k, v)| (k, v.into())).collect())
+      }
+    }
+  }
+}
+
+impl TryFrom<DType> for Value {
+  type Error = Error;
+
+  /// Convert a `DType` to a `serde_json::Value`.
+  ///
+  /// `serde_json` has no date type, so `DType::DateTime` becomes a
+  /// `Value::String` holding the RFC 3339 representation of the date.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use std::convert::TryFrom;
+  /// use sage::{json, DType, DateTime};
+  ///
+  /// let big = i64::MAX as u64 + 10;
+  /// let x = json!({ "name": "sage", "big": big, "tags": ["kg", null] });
+  ///
+  /// let value = serde_json::Value::try_from(x).unwrap();
+  /// assert_eq!(value, serde_json::json!({ "name": "sage", "big": big, "tags": ["kg", null] }));
+  ///
+  /// let flags = json!([true, false, 2.5, -7]);
+  /// let value = serde_json::Value::try_from(flags).unwrap();
+  /// assert_eq!(value, serde_json::json!([true, false, 2.5, -7]));
+  /// assert_eq!(value[2].as_f64(), Some(2.5));
+  ///
+  /// // A `DateTime` becomes its RFC 3339 string, and comes back as a plain
+  /// // `DType::String`.
+  /// let released = DType::DateTime(DateTime::from_unix(1_620_117_000));
+  /// let value = serde_json::Value::try_from(released).unwrap();
+  /// assert_eq!(value, serde_json::json!("2021-05-04T08:30:00Z"));
+  /// assert_eq!(DType::from(value), DType::String("2021-05-04T08:30:00Z".to_string()));
+  ///
+  /// # #[cfg(feature = "arbitrary_precision")]
+  /// # {
+  /// // With "arbitrary_precision", numbers keep their lexical form.
+  /// let huge: sage::DType = json::from_str("12345678901234567890123456789").unwrap();
+  /// let value = serde_json::Value::try_from(huge).unwrap();
+  /// assert_eq!(value.to_string(), "12345678901234567890123456789");
+  /// # }
+  /// ```
+  fn try_from(value: DType) -> Result<Self, Self::Error> {
+    Ok(match value {
+      DType::Null => Value::Null,
+      DType::Boolean(b) => Value::Bool(b),
+      DType::Number(n) => Value::Number(number_to_json(&n)?),
+      DType::String(s) => Value::String(s),
+      DType::DateTime(d) => Value::String(d.to_string()),
+      DType::Array(a) => Value::Array(
+        a.into_iter()
+          .map(Value::try_from)
+          .collect::<Result<_, _>>()?,
+      ),
+      DType::Object(m) => Value::Object(
+        m.into_iter()
+          .map(|(k, v)| Value::try_from(v).map(|v| (k, v)))
+          .collect::<Result<_, _>>()?,
+      ),
+    })
+  }
+}
+
+/// Error for a `DType` which doesn't hold the `expected` type.
+#[cold]
+fn invalid_type(value: &DType, expected: &str) -> Error {
+  serde::de::Error::invalid_type(value.unexpected(), &expected)
+}
+
+macro_rules! impl_try_from_dtype {
+  ($($ty:ty, $expected:literal, $variant:ident;)*) => {
+    $(
+      impl TryFrom<DType> for $ty {
+        type Error = Error;
+
+        fn try_from(value: DType) -> Result<Self, Self::Error> {
+          match value {
+            DType::$variant(v) => Ok(v),
+            other => Err(invalid_type(&other, $expected)),
+          }
+        }
+      }
+    )*
+  };
+}
+
+impl_try_from_dtype! {
+  bool, "a boolean", Boolean;
+  String, "a string", String;
+  Number, "a number", Number;
+  DateTime, "a datetime", DateTime;
+  Vec<DType>, "an array", Array;
+  Map<String, DType>, "an object", Object;
+}
+
+macro_rules! impl_try_from_dtype_number {
+  ($($ty:ty, $expected:literal, $as:ident;)*) => {
+    $(
+      impl TryFrom<DType> for $ty {
+        type Error = Error;
+
+        /// Fails unless the `DType` is a number representable as the
+        /// target type.
+        fn try_from(value: DType) -> Result<Self, Self::Error> {
+          value.$as().ok_or_else(|| invalid_type(&value, $expected))
+        }
+      }
+    )*
+  };
+}
+
+impl_try_from_dtype_number! {
+  i64, "an i64", as_i64;
+  u64, "a u64", as_u64;
+  f64, "an f64", as_f64;
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+fn number_from_json(n: &serde_json::Number) -> Option<Number> {
+  if let Some(u) = n.as_u64() {
+    Some(u.into())
+  } else if let Some(i) = n.as_i64() {
+    Some(i.into())
+  } else {
+    n.as_f64().and_then(Number::from_f64)
+  }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+fn number_from_json(n: &serde_json::Number) -> Option<Number> {
+  Some(Number::from_string_unchecked(n.to_string()))
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+fn number_to_json(n: &Number) -> Result<serde_json::Number, Error> {
+  use crate::dtype::number::NumImpl;
+
+  match n.n {
+    NumImpl::PositiveInt(u) => Ok(u.into()),
+    NumImpl::NegativeInt(i) => Ok(i.into()),
+    NumImpl::Float(f) => serde_json::Number::from_f64(f)
+      .ok_or_else(|| Error::syntax(ErrorCode::InvalidNumber, 0, 0)),
+  }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+fn number_to_json(n: &Number) -> Result<serde_json::Number, Error> {
+  serde_json::from_str(&n.n)
+    .map_err(|_| Error::syntax(ErrorCode::InvalidNumber, 0, 0))
+}
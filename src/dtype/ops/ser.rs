@@ -14,7 +14,7 @@
 
 use std::fmt;
 
-use crate::{to_dtype, DType, Error, ErrorCode, Map, Number, Result};
+use crate::{dtype::datetime, DType, Error, ErrorCode, Map, Number, Result};
 
 use serde::ser::{Impossible, Serialize};
 #[cfg(feature = "arbitrary_precision")]
@@ -48,8 +48,7 @@ impl Serialize for DType {
         }
         map.end()
       }
-      // TODO: Handle `DateTime`.
-      DType::DateTime(_) => todo!(),
+      DType::DateTime(ref d) => d.serialize(serializer),
     }
   }
 }
@@ -204,13 +203,18 @@ impl serde::Serializer for Serializer {
   #[inline]
   fn serialize_newtype_struct<T>(
     self,
-    _name: &'static str,
+    name: &'static str,
     value: &T,
   ) -> Result<DType>
   where
     T: ?Sized + Serialize,
   {
-    value.serialize(self)
+    match tri!(value.serialize(self)) {
+      DType::String(ref s) if name == datetime::TOKEN => {
+        s.parse().map(DType::DateTime)
+      }
+      value => Ok(value),
+    }
   }
 
   fn serialize_newtype_variant<T>(
@@ -224,7 +228,10 @@ impl serde::Serializer for Serializer {
     T: ?Sized + Serialize,
   {
     let mut values = Map::new();
-    values.insert(String::from(variant), tri!(to_dtype(&value)));
+    values.insert(
+      String::from(variant),
+      tri!(to_dtype_at(value, false, variant)),
+    );
     Ok(DType::Object(values))
   }
 
@@ -244,6 +251,7 @@ impl serde::Serializer for Serializer {
   fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
     Ok(SerializeVec {
       vec: Vec::with_capacity(len.unwrap_or(0)),
+      strict: false,
     })
   }
 
@@ -269,6 +277,7 @@ impl serde::Serializer for Serializer {
     Ok(SerializeTupleVariant {
       name: String::from(variant),
       vec: Vec::with_capacity(len),
+      strict: false,
     })
   }
 
@@ -276,6 +285,7 @@ impl serde::Serializer for Serializer {
     Ok(SerializeMap::Map {
       map: Map::new(),
       next_key: None,
+      strict: false,
     })
   }
 
@@ -286,9 +296,11 @@ impl serde::Serializer for Serializer {
   ) -> Result<Self::SerializeStruct> {
     match name {
       #[cfg(feature = "arbitrary_precision")]
-      crate::number::TOKEN => Ok(SerializeMap::Number { out_value: None }),
-      #[cfg(feature = "raw_value")]
-      crate::raw::TOKEN => Ok(SerializeMap::RawDType { out_value: None }),
+      crate::number::TOKEN | crate::number::SERDE_JSON_TOKEN => {
+        Ok(SerializeMap::Number { out_value: None })
+      }
+      #[cfg(feature = "raw_dtype")]
+      crate::json::TOKEN => Ok(SerializeMap::RawDType { out_value: None }),
       _ => self.serialize_map(Some(len)),
     }
   }
@@ -303,6 +315,7 @@ impl serde::Serializer for Serializer {
     Ok(SerializeStructVariant {
       name: String::from(variant),
       map: Map::new(),
+      strict: false,
     })
   }
 
@@ -314,6 +327,266 @@ impl serde::Serializer for Serializer {
   }
 }
 
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `StrictSerializer` - `Serializer` that refuses lossy conversions.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// Serializer whose output is a `DType`, failing instead of degrading values
+/// that `DType` cannot represent.
+///
+/// This is the serializer that backs
+/// [`sage::to_dtype_strict`][crate::to_dtype_strict]. It behaves like
+/// [`Serializer`] except that:
+///
+/// - `NaN` and infinite floats are an error rather than `DType::Null`.
+/// - `i128`/`u128` values outside of the `i64`/`u64` range are an error
+///   (without the `arbitrary_precision` feature).
+pub struct StrictSerializer;
+
+impl serde::Serializer for StrictSerializer {
+  type Ok = DType;
+  type Error = Error;
+
+  type SerializeSeq = SerializeVec;
+  type SerializeTuple = SerializeVec;
+  type SerializeTupleStruct = SerializeVec;
+  type SerializeTupleVariant = SerializeTupleVariant;
+  type SerializeMap = SerializeMap;
+  type SerializeStruct = SerializeMap;
+  type SerializeStructVariant = SerializeStructVariant;
+
+  #[inline]
+  fn serialize_bool(self, value: bool) -> Result<DType> {
+    Serializer.serialize_bool(value)
+  }
+
+  #[inline]
+  fn serialize_i8(self, value: i8) -> Result<DType> {
+    Serializer.serialize_i8(value)
+  }
+
+  #[inline]
+  fn serialize_i16(self, value: i16) -> Result<DType> {
+    Serializer.serialize_i16(value)
+  }
+
+  #[inline]
+  fn serialize_i32(self, value: i32) -> Result<DType> {
+    Serializer.serialize_i32(value)
+  }
+
+  #[inline]
+  fn serialize_i64(self, value: i64) -> Result<DType> {
+    Serializer.serialize_i64(value)
+  }
+
+  #[cfg(feature = "arbitrary_precision")]
+  fn serialize_i128(self, value: i128) -> Result<DType> {
+    Serializer.serialize_i128(value)
+  }
+
+  #[cfg(not(feature = "arbitrary_precision"))]
+  fn serialize_i128(self, value: i128) -> Result<DType> {
+    if let Ok(value) = i64::try_from(value) {
+      self.serialize_i64(value)
+    } else {
+      self.serialize_u128(tri!(u128::try_from(value)
+        .map_err(|_| Error::syntax(ErrorCode::NumberOutOfRange, 0, 0))))
+    }
+  }
+
+  #[inline]
+  fn serialize_u8(self, value: u8) -> Result<DType> {
+    Serializer.serialize_u8(value)
+  }
+
+  #[inline]
+  fn serialize_u16(self, value: u16) -> Result<DType> {
+    Serializer.serialize_u16(value)
+  }
+
+  #[inline]
+  fn serialize_u32(self, value: u32) -> Result<DType> {
+    Serializer.serialize_u32(value)
+  }
+
+  #[inline]
+  fn serialize_u64(self, value: u64) -> Result<DType> {
+    Serializer.serialize_u64(value)
+  }
+
+  #[cfg(feature = "arbitrary_precision")]
+  fn serialize_u128(self, value: u128) -> Result<DType> {
+    Serializer.serialize_u128(value)
+  }
+
+  #[cfg(not(feature = "arbitrary_precision"))]
+  fn serialize_u128(self, value: u128) -> Result<DType> {
+    u64::try_from(value)
+      .map_err(|_| Error::syntax(ErrorCode::NumberOutOfRange, 0, 0))
+      .and_then(|value| self.serialize_u64(value))
+  }
+
+  #[inline]
+  fn serialize_f32(self, value: f32) -> Result<DType> {
+    self.serialize_f64(value as f64)
+  }
+
+  #[inline]
+  fn serialize_f64(self, value: f64) -> Result<DType> {
+    Number::from_f64(value)
+      .map(DType::Number)
+      .ok_or_else(|| Error::syntax(ErrorCode::NonFiniteFloat, 0, 0))
+  }
+
+  #[inline]
+  fn serialize_char(self, value: char) -> Result<DType> {
+    Serializer.serialize_char(value)
+  }
+
+  #[inline]
+  fn serialize_str(self, value: &str) -> Result<DType> {
+    Serializer.serialize_str(value)
+  }
+
+  fn serialize_bytes(self, value: &[u8]) -> Result<DType> {
+    Serializer.serialize_bytes(value)
+  }
+
+  #[inline]
+  fn serialize_unit(self) -> Result<DType> {
+    Serializer.serialize_unit()
+  }
+
+  #[inline]
+  fn serialize_unit_struct(self, name: &'static str) -> Result<DType> {
+    Serializer.serialize_unit_struct(name)
+  }
+
+  #[inline]
+  fn serialize_unit_variant(
+    self,
+    name: &'static str,
+    variant_index: u32,
+    variant: &'static str,
+  ) -> Result<DType> {
+    Serializer.serialize_unit_variant(name, variant_index, variant)
+  }
+
+  #[inline]
+  fn serialize_newtype_struct<T>(
+    self,
+    name: &'static str,
+    value: &T,
+  ) -> Result<DType>
+  where
+    T: ?Sized + Serialize,
+  {
+    match tri!(value.serialize(self)) {
+      DType::String(ref s) if name == datetime::TOKEN => {
+        s.parse().map(DType::DateTime)
+      }
+      value => Ok(value),
+    }
+  }
+
+  fn serialize_newtype_variant<T>(
+    self,
+    _name: &'static str,
+    _variant_index: u32,
+    variant: &'static str,
+    value: &T,
+  ) -> Result<DType>
+  where
+    T: ?Sized + Serialize,
+  {
+    let mut values = Map::new();
+    values.insert(
+      String::from(variant),
+      tri!(to_dtype_at(value, true, variant)),
+    );
+    Ok(DType::Object(values))
+  }
+
+  #[inline]
+  fn serialize_none(self) -> Result<DType> {
+    self.serialize_unit()
+  }
+
+  #[inline]
+  fn serialize_some<T>(self, value: &T) -> Result<DType>
+  where
+    T: ?Sized + Serialize,
+  {
+    value.serialize(self)
+  }
+
+  fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+    Serializer.serialize_seq(len).map(SerializeVec::strict)
+  }
+
+  fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+    self.serialize_seq(Some(len))
+  }
+
+  fn serialize_tuple_struct(
+    self,
+    _name: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeTupleStruct> {
+    self.serialize_seq(Some(len))
+  }
+
+  fn serialize_tuple_variant(
+    self,
+    name: &'static str,
+    variant_index: u32,
+    variant: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeTupleVariant> {
+    Serializer
+      .serialize_tuple_variant(name, variant_index, variant, len)
+      .map(SerializeTupleVariant::strict)
+  }
+
+  fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap> {
+    Serializer.serialize_map(len).map(SerializeMap::strict)
+  }
+
+  fn serialize_struct(
+    self,
+    name: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeStruct> {
+    Serializer
+      .serialize_struct(name, len)
+      .map(SerializeMap::strict)
+  }
+
+  fn serialize_struct_variant(
+    self,
+    name: &'static str,
+    variant_index: u32,
+    variant: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeStructVariant> {
+    Serializer
+      .serialize_struct_variant(name, variant_index, variant, len)
+      .map(SerializeStructVariant::strict)
+  }
+
+  fn collect_str<T>(self, value: &T) -> Result<DType>
+  where
+    T: ?Sized + fmt::Display,
+  {
+    Serializer.collect_str(value)
+  }
+}
+
 /*
  * +----------------------------------------------------------------------+
  * | +------------------------------------------------------------------+ |
@@ -325,27 +598,85 @@ impl serde::Serializer for Serializer {
 
 pub struct SerializeVec {
   vec: Vec<DType>,
+  strict: bool,
 }
 
 pub struct SerializeTupleVariant {
   name: String,
   vec: Vec<DType>,
+  strict: bool,
 }
 
 pub enum SerializeMap {
   Map {
     map: Map<String, DType>,
     next_key: Option<String>,
+    strict: bool,
   },
   #[cfg(feature = "arbitrary_precision")]
   Number { out_value: Option<DType> },
-  #[cfg(feature = "raw_value")]
+  #[cfg(feature = "raw_dtype")]
   RawDType { out_value: Option<DType> },
 }
 
 pub struct SerializeStructVariant {
   name: String,
   map: Map<String, DType>,
+  strict: bool,
+}
+
+impl SerializeVec {
+  fn strict(self) -> Self {
+    SerializeVec {
+      strict: true,
+      ..self
+    }
+  }
+}
+
+impl SerializeTupleVariant {
+  fn strict(self) -> Self {
+    SerializeTupleVariant {
+      strict: true,
+      ..self
+    }
+  }
+}
+
+impl SerializeMap {
+  fn strict(mut self) -> Self {
+    match self {
+      SerializeMap::Map { ref mut strict, .. } => *strict = true,
+      #[cfg(any(feature = "arbitrary_precision", feature = "raw_dtype"))]
+      _ => {}
+    }
+    self
+  }
+}
+
+impl SerializeStructVariant {
+  fn strict(self) -> Self {
+    SerializeStructVariant {
+      strict: true,
+      ..self
+    }
+  }
+}
+
+// Serialize a nested value with the same strictness as its parent, recording
+// `token` in the error's path should it fail. The token is only formatted on
+// error, keeping the success path allocation free.
+fn to_dtype_at<T, K>(value: &T, strict: bool, token: K) -> Result<DType>
+where
+  T: ?Sized + Serialize,
+  K: fmt::Display,
+{
+  let value = if strict {
+    value.serialize(StrictSerializer)
+  } else {
+    value.serialize(Serializer)
+  };
+  value.map_err(|err| err.prepend_path(&token.to_string()))
 }
 
 /*
@@ -364,7 +695,8 @@ impl serde::ser::SerializeSeq for SerializeVec {
   where
     T: ?Sized + Serialize,
   {
-    self.vec.push(tri!(to_dtype(&value)));
+    let index = self.vec.len();
+    self.vec.push(tri!(to_dtype_at(value, self.strict, index)));
     Ok(())
   }
 
@@ -421,7 +753,10 @@ impl serde::ser::SerializeTupleVariant for SerializeTupleVariant {
   where
     T: ?Sized + Serialize,
   {
-    self.vec.push(tri!(to_dtype(&value)));
+    let index = self.vec.len();
+    let value = tri!(to_dtype_at(value, self.strict, index)
+      .map_err(|err| err.prepend_path(&self.name)));
+    self.vec.push(value);
     Ok(())
   }
 
@@ -459,7 +794,7 @@ impl serde::ser::SerializeMap for SerializeMap {
       }
       #[cfg(feature = "arbitrary_precision")]
       SerializeMap::Number { .. } => unreachable!(),
-      #[cfg(feature = "raw_value")]
+      #[cfg(feature = "raw_dtype")]
       SerializeMap::RawDType { .. } => unreachable!(),
     }
   }
@@ -472,17 +807,19 @@ impl serde::ser::SerializeMap for SerializeMap {
       SerializeMap::Map {
         ref mut map,
         ref mut next_key,
+        strict,
       } => {
         let key = next_key.take();
         // Panic because this indicates a bug in the program rather than an
         // expected failure.
         let key = key.expect("serialize_value called before serialize_key");
-        map.insert(key, tri!(to_dtype(&value)));
+        let value = tri!(to_dtype_at(value, strict, &key));
+        map.insert(key, value);
         Ok(())
       }
       #[cfg(feature = "arbitrary_precision")]
       SerializeMap::Number { .. } => unreachable!(),
-      #[cfg(feature = "raw_value")]
+      #[cfg(feature = "raw_dtype")]
       SerializeMap::RawDType { .. } => unreachable!(),
     }
   }
@@ -492,7 +829,7 @@ impl serde::ser::SerializeMap for SerializeMap {
       SerializeMap::Map { map, .. } => Ok(DType::Object(map)),
       #[cfg(feature = "arbitrary_precision")]
       SerializeMap::Number { .. } => unreachable!(),
-      #[cfg(feature = "raw_value")]
+      #[cfg(feature = "raw_dtype")]
       SerializeMap::RawDType { .. } => unreachable!(),
     }
   }
@@ -710,16 +1047,17 @@ impl serde::ser::SerializeStruct for SerializeMap {
       }
       #[cfg(feature = "arbitrary_precision")]
       SerializeMap::Number { ref mut out_value } => {
-        if key == crate::number::TOKEN {
+        if key == crate::number::TOKEN || key == crate::number::SERDE_JSON_TOKEN
+        {
           *out_value = Some(value.serialize(NumberDTypeEmitter)?);
           Ok(())
         } else {
           Err(invalid_number())
         }
       }
-      #[cfg(feature = "raw_value")]
+      #[cfg(feature = "raw_dtype")]
       SerializeMap::RawDType { ref mut out_value } => {
-        if key == crate::raw::TOKEN {
+        if key == crate::json::TOKEN {
           *out_value = Some(value.serialize(RawDTypeEmitter)?);
           Ok(())
         } else {
@@ -736,7 +1074,7 @@ impl serde::ser::SerializeStruct for SerializeMap {
       SerializeMap::Number { out_value, .. } => {
         Ok(out_value.expect("number value was not emitted"))
       }
-      #[cfg(feature = "raw_value")]
+      #[cfg(feature = "raw_dtype")]
       SerializeMap::RawDType { out_value, .. } => {
         Ok(out_value.expect("raw value was not emitted"))
       }
@@ -760,7 +1098,9 @@ impl serde::ser::SerializeStructVariant for SerializeStructVariant {
   where
     T: ?Sized + Serialize,
   {
-    self.map.insert(String::from(key), tri!(to_dtype(&value)));
+    let value = tri!(to_dtype_at(value, self.strict, key)
+      .map_err(|err| err.prepend_path(&self.name)));
+    self.map.insert(String::from(key), value);
     Ok(())
   }
 
@@ -968,15 +1308,15 @@ impl serde::ser::Serializer for NumberDTypeEmitter {
  * +----------------------------------------------------------------------+
 */
 
-#[cfg(feature = "raw_value")]
+#[cfg(feature = "raw_dtype")]
 struct RawDTypeEmitter;
 
-#[cfg(feature = "raw_value")]
+#[cfg(feature = "raw_dtype")]
 fn invalid_raw_value() -> Error {
-  Error::syntax(ErrorCode::ExpectedSomeDType, 0, 0)
+  Error::syntax(ErrorCode::ExpectedSomeValue, 0, 0)
 }
 
-#[cfg(feature = "raw_value")]
+#[cfg(feature = "raw_dtype")]
 impl serde::ser::Serializer for RawDTypeEmitter {
   type Ok = DType;
   type Error = Error;
@@ -1038,7 +1378,7 @@ impl serde::ser::Serializer for RawDTypeEmitter {
   }
 
   fn serialize_str(self, value: &str) -> Result<DType> {
-    crate::from_str(value)
+    crate::json::from_str(value)
   }
 
   fn serialize_bytes(self, _value: &[u8]) -> Result<DType> {
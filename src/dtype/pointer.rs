@@ -0,0 +1,291 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Entry API over a [JSON Pointer] path into a `DType`.
+//!
+//! [JSON Pointer]: https://tools.ietf.org/html/rfc6901
+
+use std::mem;
+
+use crate::DType;
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `PointerEntry`, `VacantPointerEntry` & `OccupiedPointerEntry`.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// A view into a single location of a `DType`, addressed by a JSON Pointer,
+/// which may either be vacant or occupied.
+///
+/// This enum is constructed from [`DType::entry_pointer`].
+pub enum PointerEntry<'a> {
+  /// A vacant PointerEntry.
+  Vacant(VacantPointerEntry<'a>),
+  /// An occupied PointerEntry.
+  Occupied(OccupiedPointerEntry<'a>),
+}
+
+/// A vacant PointerEntry. It is part of the [`PointerEntry`] enum.
+pub struct VacantPointerEntry<'a> {
+  root: &'a mut DType,
+  pointer: String,
+}
+
+/// An occupied PointerEntry. It is part of the [`PointerEntry`] enum.
+pub struct OccupiedPointerEntry<'a> {
+  root: &'a mut DType,
+  pointer: String,
+}
+
+// Split a JSON Pointer into its unescaped reference tokens.
+fn tokens(pointer: &str) -> impl Iterator<Item = String> + '_ {
+  pointer
+    .split('/')
+    .skip(1)
+    .map(|x| x.replace("~1", "/").replace("~0", "~"))
+}
+
+impl<'a> PointerEntry<'a> {
+  pub(crate) fn new(
+    root: &'a mut DType,
+    pointer: &str,
+  ) -> Option<PointerEntry<'a>> {
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+      return None;
+    }
+    let pointer = pointer.to_string();
+    if root.pointer(&pointer).is_some() {
+      return Some(PointerEntry::Occupied(OccupiedPointerEntry {
+        root,
+        pointer,
+      }));
+    }
+
+    // Follow the path as far as it exists. What is left can only be created
+    // under an object (or `null`, which becomes one).
+    let mut target = &*root;
+    for token in tokens(&pointer) {
+      target = match *target {
+        DType::Object(ref map) => match map.get(&token) {
+          Some(value) => value,
+          None => break,
+        },
+        DType::Array(ref list) => list.get(DType::parse_index(&token)?)?,
+        DType::Null => break,
+        _ => return None,
+      };
+    }
+    Some(PointerEntry::Vacant(VacantPointerEntry { root, pointer }))
+  }
+
+  /// Returns this entry's JSON Pointer.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use sage::json;
+  /// #
+  /// let mut data = json!({});
+  /// assert_eq!(data.entry_pointer("/a/b").unwrap().pointer(), "/a/b");
+  /// ```
+  pub fn pointer(&self) -> &str {
+    match *self {
+      PointerEntry::Vacant(ref e) => &e.pointer,
+      PointerEntry::Occupied(ref e) => &e.pointer,
+    }
+  }
+
+  /// Ensures a value is in the entry by inserting the default if empty, and
+  /// returns a mutable reference to the value in the entry.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use sage::json;
+  /// #
+  /// let mut data = json!({ "a": {} });
+  /// data.entry_pointer("/a/b/c").unwrap().or_insert(json!(12));
+  ///
+  /// assert_eq!(data, json!({ "a": { "b": { "c": 12 } } }));
+  /// ```
+  pub fn or_insert(self, default: DType) -> &'a mut DType {
+    match self {
+      PointerEntry::Vacant(entry) => entry.insert(default),
+      PointerEntry::Occupied(entry) => entry.into_mut(),
+    }
+  }
+
+  /// Ensures a value is in the entry by inserting the result of the default
+  /// function if empty, and returns a mutable reference to the value in the
+  /// entry.
+  pub fn or_insert_with<F>(self, default: F) -> &'a mut DType
+  where
+    F: FnOnce() -> DType,
+  {
+    match self {
+      PointerEntry::Vacant(entry) => entry.insert(default()),
+      PointerEntry::Occupied(entry) => entry.into_mut(),
+    }
+  }
+
+  /// Ensures a value is in the entry by inserting `DType::default()`
+  /// (`Null`) if empty, and returns a mutable reference to the value in the
+  /// entry.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use sage::json;
+  /// #
+  /// let mut data = json!({});
+  /// for _ in 0..3 {
+  ///   let n = data.entry_pointer("/stats/hits").unwrap().or_default();
+  ///   *n = json!(n.as_u64().unwrap_or(0) + 1);
+  /// }
+  ///
+  /// assert_eq!(data, json!({ "stats": { "hits": 3 } }));
+  /// ```
+  pub fn or_default(self) -> &'a mut DType {
+    self.or_insert_with(DType::default)
+  }
+
+  /// Provides in-place mutable access to an occupied entry before any
+  /// potential inserts.
+  pub fn and_modify<F>(self, f: F) -> Self
+  where
+    F: FnOnce(&mut DType),
+  {
+    match self {
+      PointerEntry::Occupied(mut entry) => {
+        f(entry.get_mut());
+        PointerEntry::Occupied(entry)
+      }
+      PointerEntry::Vacant(entry) => PointerEntry::Vacant(entry),
+    }
+  }
+}
+
+impl<'a> VacantPointerEntry<'a> {
+  /// Gets the JSON Pointer that would be used when inserting a value.
+  pub fn pointer(&self) -> &str {
+    &self.pointer
+  }
+
+  /// Sets the value at the entry's JSON Pointer, creating any missing
+  /// intermediate objects, and returns a mutable reference to it.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use sage::json;
+  /// #
+  /// use sage::pointer::PointerEntry;
+  ///
+  /// let mut data = json!({ "a": null });
+  ///
+  /// match data.entry_pointer("/a/b~1c").unwrap() {
+  ///   PointerEntry::Vacant(vacant) => {
+  ///     vacant.insert(json!(true));
+  ///   }
+  ///   PointerEntry::Occupied(_) => unimplemented!(),
+  /// }
+  ///
+  /// assert_eq!(data, json!({ "a": { "b/c": true } }));
+  /// ```
+  pub fn insert(self, value: DType) -> &'a mut DType {
+    let mut target = self.root;
+    for token in tokens(&self.pointer) {
+      if target.is_null() {
+        *target = DType::Object(Default::default());
+      }
+      target = match *target {
+        DType::Object(ref mut map) => map.entry(token).or_insert(DType::Null),
+        DType::Array(ref mut list) => {
+          let index = DType::parse_index(&token).unwrap();
+          &mut list[index]
+        }
+        // `PointerEntry::new` only hands out vacant entries whose path
+        // leads through objects, arrays & nulls.
+        _ => unreachable!(),
+      };
+    }
+    *target = value;
+    target
+  }
+}
+
+impl<'a> OccupiedPointerEntry<'a> {
+  /// Gets the JSON Pointer of the entry.
+  pub fn pointer(&self) -> &str {
+    &self.pointer
+  }
+
+  /// Gets a reference to the value in the entry.
+  pub fn get(&self) -> &DType {
+    self.root.pointer(&self.pointer).unwrap()
+  }
+
+  /// Gets a mutable reference to the value in the entry.
+  pub fn get_mut(&mut self) -> &mut DType {
+    self.root.pointer_mut(&self.pointer).unwrap()
+  }
+
+  /// Converts the entry into a mutable reference to its value.
+  pub fn into_mut(self) -> &'a mut DType {
+    self.root.pointer_mut(&self.pointer).unwrap()
+  }
+
+  /// Sets the value of the entry, and returns the entry's old value.
+  pub fn insert(&mut self, value: DType) -> DType {
+    mem::replace(self.get_mut(), value)
+  }
+
+  /// Takes the value of the entry out of its parent, and returns it.
+  ///
+  /// Removing the root (the empty pointer `""`) leaves `Null` in its place.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use sage::json;
+  /// #
+  /// use sage::pointer::PointerEntry;
+  ///
+  /// let mut data = json!({ "a": [1, 2, 3] });
+  ///
+  /// match data.entry_pointer("/a/1").unwrap() {
+  ///   PointerEntry::Occupied(occupied) => assert_eq!(occupied.remove(), 2),
+  ///   PointerEntry::Vacant(_) => unimplemented!(),
+  /// }
+  ///
+  /// assert_eq!(data, json!({ "a": [1, 3] }));
+  /// ```
+  pub fn remove(self) -> DType {
+    let split = match self.pointer.rfind('/') {
+      Some(split) => split,
+      None => return self.root.take(),
+    };
+    let token = tokens(&self.pointer[split..]).next().unwrap();
+    match *self.root.pointer_mut(&self.pointer[..split]).unwrap() {
+      DType::Object(ref mut map) => map.remove(&token).unwrap(),
+      DType::Array(ref mut list) => {
+        list.remove(DType::parse_index(&token).unwrap())
+      }
+      _ => unreachable!(),
+    }
+  }
+}
@@ -0,0 +1,251 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configurable, human-friendly rendering of a `DType` as JSON.
+
+use crate::{datastore::json, DType};
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `PrettyPrintConfig`.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// Options for [`DType::pretty_print`].
+///
+/// # Example
+///
+/// ```rust
+/// use sage::dtype::PrettyPrintConfig;
+///
+/// let config = PrettyPrintConfig {
+///   indent: 4,
+///   truncate_strings: Some(20),
+///   ..PrettyPrintConfig::default()
+/// };
+/// assert_eq!(config.max_width, 80);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrettyPrintConfig {
+  /// Number of spaces per nesting level.
+  pub indent: usize,
+
+  /// Arrays & objects which fit within `max_width` columns (indentation
+  /// included) are kept on one line; wider ones are broken over several.
+  pub max_width: usize,
+
+  /// Highlights keys & values with ANSI color codes. Only takes effect with
+  /// the `termcolor` feature; colored output is no longer valid JSON.
+  pub color: bool,
+
+  /// Shortens strings longer than this many characters, as
+  /// [`DType::truncate_strings`] does.
+  pub truncate_strings: Option<usize>,
+}
+
+impl Default for PrettyPrintConfig {
+  fn default() -> Self {
+    PrettyPrintConfig {
+      indent: 2,
+      max_width: 80,
+      color: false,
+      truncate_strings: None,
+    }
+  }
+}
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | Printer.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// What is being painted, which decides its color.
+#[derive(Clone, Copy)]
+enum Style {
+  Key,
+  String,
+  Number,
+  Boolean,
+  Null,
+  Punct,
+}
+
+pub(crate) struct Printer<'a> {
+  config: &'a PrettyPrintConfig,
+  out: String,
+}
+
+impl<'a> Printer<'a> {
+  pub(crate) fn print(value: &DType, config: &'a PrettyPrintConfig) -> String {
+    let mut printer = Printer {
+      config,
+      out: String::new(),
+    };
+    match config.truncate_strings {
+      Some(max_len) => printer.value(&value.truncate_strings(max_len), 0, 0),
+      None => printer.value(value, 0, 0),
+    }
+    printer.out
+  }
+
+  /// Writes `value` at nesting `level`, where `used` columns of the current
+  /// line are already taken (indentation, key, ...).
+  fn value(&mut self, value: &DType, level: usize, used: usize) {
+    match value {
+      DType::Array(v) if !v.is_empty() => {
+        if used + single_line_width(value) <= self.config.max_width {
+          return self.single_line(value);
+        }
+        self.paint("[", Style::Punct);
+        for (i, item) in v.iter().enumerate() {
+          let indent = self.newline(level + 1);
+          self.value(item, level + 1, indent);
+          if i + 1 < v.len() {
+            self.paint(",", Style::Punct);
+          }
+        }
+        self.newline(level);
+        self.paint("]", Style::Punct);
+      }
+      DType::Object(m) if !m.is_empty() => {
+        if used + single_line_width(value) <= self.config.max_width {
+          return self.single_line(value);
+        }
+        self.paint("{", Style::Punct);
+        for (i, (k, v)) in m.iter().enumerate() {
+          let indent = self.newline(level + 1);
+          let key = quote(k);
+          self.paint(&key, Style::Key);
+          self.paint(": ", Style::Punct);
+          self.value(v, level + 1, indent + key.chars().count() + 2);
+          if i + 1 < m.len() {
+            self.paint(",", Style::Punct);
+          }
+        }
+        self.newline(level);
+        self.paint("}", Style::Punct);
+      }
+      _ => self.single_line(value),
+    }
+  }
+
+  /// Writes `value` on a single line, e.g. `{"a": [1, 2]}`.
+  fn single_line(&mut self, value: &DType) {
+    match value {
+      DType::Array(v) => {
+        self.paint("[", Style::Punct);
+        for (i, item) in v.iter().enumerate() {
+          if i > 0 {
+            self.paint(", ", Style::Punct);
+          }
+          self.single_line(item);
+        }
+        self.paint("]", Style::Punct);
+      }
+      DType::Object(m) => {
+        self.paint("{", Style::Punct);
+        for (i, (k, v)) in m.iter().enumerate() {
+          if i > 0 {
+            self.paint(", ", Style::Punct);
+          }
+          self.paint(&quote(k), Style::Key);
+          self.paint(": ", Style::Punct);
+          self.single_line(v);
+        }
+        self.paint("}", Style::Punct);
+      }
+      DType::Boolean(_) => self.paint(&scalar(value), Style::Boolean),
+      DType::Null => self.paint("null", Style::Null),
+      DType::Number(_) => self.paint(&scalar(value), Style::Number),
+      DType::DateTime(_) | DType::String(_) => {
+        self.paint(&scalar(value), Style::String)
+      }
+    }
+  }
+
+  /// Starts a new line indented for `level`, returning the indentation.
+  fn newline(&mut self, level: usize) -> usize {
+    let indent = level * self.config.indent;
+    self.out.push('\n');
+    self.out.push_str(&" ".repeat(indent));
+    indent
+  }
+
+  #[cfg(not(feature = "termcolor"))]
+  fn paint(&mut self, text: &str, _style: Style) {
+    self.out.push_str(text);
+  }
+
+  #[cfg(feature = "termcolor")]
+  fn paint(&mut self, text: &str, style: Style) {
+    use std::io::Write;
+    use termcolor::{Ansi, Color, ColorSpec, WriteColor};
+
+    let color = match style {
+      _ if !self.config.color => None,
+      Style::Key => Some(Color::Blue),
+      Style::String => Some(Color::Green),
+      Style::Number => Some(Color::Cyan),
+      Style::Boolean => Some(Color::Yellow),
+      Style::Null => Some(Color::Magenta),
+      Style::Punct => None,
+    };
+    let color = match color {
+      Some(color) => color,
+      None => return self.out.push_str(text),
+    };
+
+    // Writing into a `Vec` can't fail.
+    let mut ansi = Ansi::new(Vec::new());
+    let _ = ansi.set_color(ColorSpec::new().set_fg(Some(color)));
+    let _ = ansi.write_all(text.as_bytes());
+    let _ = ansi.reset();
+    self
+      .out
+      .push_str(&String::from_utf8_lossy(&ansi.into_inner()));
+  }
+}
+
+/// Width of `value` printed on a single line, without color codes.
+fn single_line_width(value: &DType) -> usize {
+  match value {
+    DType::Array(v) => {
+      2 + v.iter().map(single_line_width).sum::<usize>()
+        + 2 * v.len().saturating_sub(1)
+    }
+    DType::Object(m) => {
+      2 + m
+        .iter()
+        .map(|(k, v)| quote(k).chars().count() + 2 + single_line_width(v))
+        .sum::<usize>()
+        + 2 * m.len().saturating_sub(1)
+    }
+    _ => scalar(value).chars().count(),
+  }
+}
+
+/// A JSON string literal for `s`.
+fn quote(s: &str) -> String {
+  json::to_string(s).unwrap_or_default()
+}
+
+/// The JSON text of a scalar `value`.
+fn scalar(value: &DType) -> String {
+  json::to_string(value).unwrap_or_default()
+}
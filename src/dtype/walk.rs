@@ -0,0 +1,230 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traversals of a `DType` tree using an explicit stack rather than
+//! recursion, so arbitrarily deep values (e.g. parsed with the
+//! `unbounded_depth` feature) can't overflow the call stack.
+
+use std::{mem, slice};
+
+use crate::{dtype::map, DType, Map};
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `DepthFirst`.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// Iterates over a `DType` and every value nested in it, depth-first, a
+/// parent before its children & children in order.
+pub(crate) struct DepthFirst<'a> {
+  root: Option<&'a DType>,
+  stack: Vec<Children<'a>>,
+}
+
+enum Children<'a> {
+  Array(slice::Iter<'a, DType>),
+  Object(map::Values<'a>),
+}
+
+impl<'a> DepthFirst<'a> {
+  pub(crate) fn new(value: &'a DType) -> DepthFirst<'a> {
+    DepthFirst {
+      root: Some(value),
+      stack: Vec::new(),
+    }
+  }
+
+  fn descend(&mut self, value: &'a DType) {
+    match value {
+      DType::Array(v) => self.stack.push(Children::Array(v.iter())),
+      DType::Object(m) => self.stack.push(Children::Object(m.values())),
+      _ => {}
+    }
+  }
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+  type Item = &'a DType;
+
+  fn next(&mut self) -> Option<&'a DType> {
+    if let Some(root) = self.root.take() {
+      self.descend(root);
+      return Some(root);
+    }
+    loop {
+      let next = match self.stack.last_mut()? {
+        Children::Array(iter) => iter.next(),
+        Children::Object(iter) => iter.next(),
+      };
+      match next {
+        Some(value) => {
+          self.descend(value);
+          return Some(value);
+        }
+        None => {
+          self.stack.pop();
+        }
+      }
+    }
+  }
+}
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `strip`.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// An array or object being copied by `strip`, with its remaining children.
+enum Frame<'a> {
+  Array(slice::Iter<'a, DType>, Vec<DType>),
+  /// `key` is the key of the child currently being copied.
+  Object(map::Iter<'a>, Map<String, DType>, String),
+}
+
+impl<'a> Frame<'a> {
+  fn next_child(&mut self) -> Option<&'a DType> {
+    match self {
+      Frame::Array(iter, _) => iter.next(),
+      Frame::Object(iter, _, key) => iter.next().map(|(k, v)| {
+        key.clone_from(k);
+        v
+      }),
+    }
+  }
+
+  /// The key of the child currently being copied, if this is an object.
+  fn key(&self) -> Option<&str> {
+    match self {
+      Frame::Array(..) => None,
+      Frame::Object(_, _, key) => Some(key),
+    }
+  }
+
+  fn push(&mut self, child: DType) {
+    match self {
+      Frame::Array(_, v) => v.push(child),
+      Frame::Object(_, m, key) => {
+        m.insert(mem::take(key), child);
+      }
+    }
+  }
+
+  fn into_dtype(self) -> DType {
+    match self {
+      Frame::Array(_, v) => DType::Array(v),
+      Frame::Object(_, m, _) => DType::Object(m),
+    }
+  }
+}
+
+/// Copies `value`, dropping nested values that match `drop` once their own
+/// children have been stripped. `value` itself is never dropped.
+pub(crate) fn strip<F>(value: &DType, drop: &F) -> DType
+where
+  F: Fn(&DType) -> bool,
+{
+  let mut stack: Vec<Frame<'_>> = Vec::new();
+  let mut next = Some(value);
+  loop {
+    // Start copying `next`: scalars are done at once, arrays & objects once
+    // all their children are.
+    let mut done = match next.take() {
+      Some(DType::Array(v)) => {
+        stack.push(Frame::Array(v.iter(), Vec::with_capacity(v.len())));
+        None
+      }
+      Some(DType::Object(m)) => {
+        stack.push(Frame::Object(m.iter(), Map::new(), String::new()));
+        None
+      }
+      Some(other) => Some(other.clone()),
+      None => None,
+    };
+
+    // Hand finished copies to their parent until one has children left.
+    while next.is_none() {
+      let frame = match stack.last_mut() {
+        Some(frame) => frame,
+        None => return done.unwrap_or(DType::Null),
+      };
+      if let Some(child) = done.take() {
+        if !drop(&child) {
+          frame.push(child);
+        }
+      }
+      next = frame.next_child();
+      if next.is_none() {
+        done = stack.pop().map(Frame::into_dtype);
+      }
+    }
+  }
+}
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `map`.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// Copies `value`, except that any value (`value` itself included) for which
+/// `replace` returns `Some` is swapped for the result instead of being
+/// copied or descended into. `replace` also gets the value's key when it's
+/// an object entry.
+pub(crate) fn map<F>(value: &DType, mut replace: F) -> DType
+where
+  F: FnMut(Option<&str>, &DType) -> Option<DType>,
+{
+  let mut stack: Vec<Frame<'_>> = Vec::new();
+  let mut done = replace(None, value);
+  let mut next = if done.is_none() { Some(value) } else { None };
+  loop {
+    match next.take() {
+      Some(DType::Array(v)) => {
+        stack.push(Frame::Array(v.iter(), Vec::with_capacity(v.len())));
+      }
+      Some(DType::Object(m)) => {
+        stack.push(Frame::Object(m.iter(), Map::new(), String::new()));
+      }
+      Some(other) => done = Some(other.clone()),
+      None => {}
+    }
+
+    // Hand finished copies to their parent until one has a child left that
+    // `replace` doesn't take care of.
+    while next.is_none() {
+      let frame = match stack.last_mut() {
+        Some(frame) => frame,
+        None => return done.unwrap_or(DType::Null),
+      };
+      if let Some(child) = done.take() {
+        frame.push(child);
+      }
+      match frame.next_child() {
+        Some(child) => match replace(frame.key(), child) {
+          Some(replaced) => done = Some(replaced),
+          None => next = Some(child),
+        },
+        None => done = stack.pop().map(Frame::into_dtype),
+      }
+    }
+  }
+}
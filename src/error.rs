@@ -51,11 +51,43 @@ impl Debug for Error {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     write!(
       f,
-      "Error({:?}, line: {}, column: {})",
+      "Error({:?}, line: {}, column: {}",
       self.err.code.to_string(),
       self.err.line,
       self.err.column
-    )
+    )?;
+    if let Some(ref path) = self.err.path {
+      write!(f, ", path: {:?}", path)?;
+    }
+    if let Some(ref loc) = self.err.location {
+      write!(f, ", location: {}", loc)?;
+    }
+    f.write_str(")")
+  }
+}
+
+/// The place in Rust source code where an `Error` was raised.
+///
+/// Unlike [`Error::line`] and [`Error::column`], which point into the *input*
+/// being parsed, a `SourceLocation` points at the code that constructed the
+/// error. It is attached with [`Error::with_location`] and is mostly useful
+/// when debugging graph operations that fail far from any input text.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SourceLocation {
+  /// Source file the error originated from, if known.
+  pub file: Option<String>,
+  /// One-based line number within `file`.
+  pub line: u32,
+  /// One-based column number within `file`.
+  pub column: u32,
+}
+
+impl Display for SourceLocation {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self.file {
+      Some(ref file) => write!(f, "{}:{}:{}", file, self.line, self.column),
+      None => write!(f, "<unknown>:{}:{}", self.line, self.column),
+    }
   }
 }
 
@@ -87,7 +119,7 @@ impl Error {
   /// - `Category::Eof` - unexpected end of the input data
   pub fn classify(&self) -> Category {
     match self.err.code {
-      ErrorCode::Message(_) => Category::Data,
+      ErrorCode::Message(_) | ErrorCode::NonFiniteFloat => Category::Data,
 
       ErrorCode::Io(_) | ErrorCode::Json(_) => Category::Io,
 
@@ -117,7 +149,8 @@ impl Error {
       | ErrorCode::TrailingCharacters
       | ErrorCode::UnexpectedEndOfHexEscape
       | ErrorCode::RecursionLimitExceeded
-      | ErrorCode::RegexParser => Category::Syntax,
+      | ErrorCode::RegexParser
+      | ErrorCode::InvalidIri => Category::Syntax,
     }
   }
 
@@ -150,6 +183,66 @@ impl Error {
   pub fn is_eof(&self) -> bool {
     self.classify() == Category::Eof
   }
+
+  /// Where in the Rust source this error was raised, if it was recorded.
+  ///
+  /// Errors raised inside `sage` itself carry a location; errors produced
+  /// by the JSON parser or through `serde` generally do not.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::dtype::DateTime;
+  ///
+  /// let err = "yesterday".parse::<DateTime>().unwrap_err();
+  /// let loc = err.source_location().unwrap();
+  /// assert!(loc.file.as_deref().unwrap().ends_with("datetime.rs"));
+  /// ```
+  pub fn source_location(&self) -> Option<&SourceLocation> {
+    self.err.location.as_ref()
+  }
+
+  /// JSON Pointer to the offending value when the error was raised while
+  /// converting a nested value, e.g. by [`to_dtype_strict`].
+  ///
+  /// [`to_dtype_strict`]: crate::to_dtype_strict
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use std::collections::BTreeMap;
+  ///
+  /// let mut map = BTreeMap::new();
+  /// map.insert("scores", vec![1.0, f64::NAN]);
+  ///
+  /// let err = sage::to_dtype_strict(&map).unwrap_err();
+  /// assert_eq!(err.path(), Some("/scores/1"));
+  /// ```
+  pub fn path(&self) -> Option<&str> {
+    self.err.path.as_deref()
+  }
+
+  /// Attach (or replace) the source location of this error.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::error::SourceLocation;
+  /// use sage::dtype::DateTime;
+  ///
+  /// let loc = SourceLocation {
+  ///   file: Some("loader.rs".to_string()),
+  ///   line: 12,
+  ///   column: 5,
+  /// };
+  /// let err = "yesterday".parse::<DateTime>().unwrap_err();
+  /// let err = err.with_location(loc.clone());
+  /// assert_eq!(err.source_location(), Some(&loc));
+  /// ```
+  pub fn with_location(mut self, loc: SourceLocation) -> Error {
+    self.err.location = Some(loc);
+    self
+  }
 }
 
 impl Error {
@@ -158,10 +251,36 @@ impl Error {
   #[cold]
   pub(crate) fn syntax(code: ErrorCode, line: usize, column: usize) -> Self {
     Error {
-      err: Box::new(ErrorImpl { code, line, column }),
+      err: Box::new(ErrorImpl {
+        code,
+        line,
+        column,
+        context: None,
+        path: None,
+        location: None,
+      }),
     }
   }
 
+  // Prepend a JSON Pointer reference token to the error's path, escaping it
+  // as described in RFC 6901. Used while unwinding nested serialization.
+  #[doc(hidden)]
+  #[cold]
+  pub(crate) fn prepend_path(mut self, token: &str) -> Self {
+    let token = token.replace('~', "~0").replace('/', "~1");
+    let rest = self.err.path.take().unwrap_or_default();
+    self.err.path = Some(format!("/{}{}", token, rest));
+    self
+  }
+
+  // Used by `sage_err!`; not public API.
+  #[doc(hidden)]
+  #[cold]
+  pub(crate) fn with_context(mut self, context: String) -> Self {
+    self.err.context = Some(context.into_boxed_str());
+    self
+  }
+
   #[doc(hidden)]
   #[cold]
   pub(crate) fn io(error: io::Error) -> Self {
@@ -170,6 +289,9 @@ impl Error {
         code: ErrorCode::Io(error),
         line: 0,
         column: 0,
+        context: None,
+        path: None,
+        location: None,
       }),
     }
   }
@@ -258,6 +380,11 @@ struct ErrorImpl {
   code: ErrorCode,
   line: usize,
   column: usize,
+  /// Free-form message prefixed to `code` when displayed.
+  context: Option<Box<str>>,
+  /// JSON Pointer to the value that caused the error, if known.
+  path: Option<String>,
+  location: Option<SourceLocation>,
 }
 
 // Not public API. Should be pub(crate).
@@ -354,6 +481,12 @@ pub(crate) enum ErrorCode {
 
   /// Could not parse regular expression pattern or pattern wasn't a match.
   RegexParser,
+
+  /// A `NaN` or infinite float was serialized in strict mode.
+  NonFiniteFloat,
+
+  /// An IRI was malformed.
+  InvalidIri,
 }
 
 impl Display for ErrorCode {
@@ -409,6 +542,10 @@ impl Display for ErrorCode {
       ErrorCode::RegexParser => {
         f.write_str("regular expression wasn't a match or malformed.")
       }
+      ErrorCode::NonFiniteFloat => {
+        f.write_str("non-finite float cannot be represented")
+      }
+      ErrorCode::InvalidIri => f.write_str("invalid IRI"),
     }
   }
 }
@@ -441,14 +578,17 @@ impl Display for Error {
 
 impl Display for ErrorImpl {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if let Some(ref context) = self.context {
+      write!(f, "{}: ", context)?;
+    }
+    Display::fmt(&self.code, f)?;
+    if let Some(ref path) = self.path {
+      write!(f, " at `{}`", path)?;
+    }
     if self.line == 0 {
-      Display::fmt(&self.code, f)
+      Ok(())
     } else {
-      write!(
-        f,
-        "{} at line {} column {}",
-        self.code, self.line, self.column
-      )
+      write!(f, " at line {} column {}", self.line, self.column)
     }
   }
 }
@@ -485,6 +625,9 @@ fn make_error(mut msg: String) -> Error {
       code: ErrorCode::Message(msg.into_boxed_str()),
       line,
       column,
+      context: None,
+      path: None,
+      location: None,
     }),
   }
 }
@@ -12,14 +12,49 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! `sage::graph` contains the building blocks of a `sage` Knowledge Graph:
+//! `Entity`s, kept in a `NodeStore`, connected to one another by a
+//! `Predicate` through a `Connection`, forming a `Triple`.
+//!
+//! Every public graph type can be cloned, compared & debug-printed:
+//!
+//! ```rust
+//! use std::fmt::Debug;
+//!
+//! use sage::graph::{Connection, Entity, Node, NodeStore, Predicate, Triple};
+//! use sage::vocab::Namespace;
+//!
+//! fn check<T: Clone + Debug + PartialEq>(value: T) {
+//!   let copy = value.clone();
+//!   assert_eq!(copy, value);
+//!   assert!(!format!("{:?}", copy).is_empty());
+//! }
+//!
+//! check(Node::Blank(Some("b0".to_string())));
+//! check(Node::Multiple(vec![Node::Schema, Node::Literal(sage::json!(1))]));
+//! check(Entity::schema("schema:Movie", "Avatar"));
+//! check(NodeStore::new());
+//! check(Predicate::Uri(Namespace::default()));
+//! check(Connection::Shared);
+//!
+//! let (a, b) = (Entity::literal(1), Entity::literal(2));
+//! let p = Predicate::Literal("next".to_string());
+//! check(Triple::new(a.id(), p, b.id(), Connection::Forward));
+//! ```
+
 mod connection;
+mod id;
 mod node;
 mod predicate;
 mod triple;
 
 pub use connection::Connection;
-pub use node::{Node, NodeStore};
-pub use predicate::Predicate;
-pub use triple::Triple;
+pub use id::IdGenerator;
+pub use node::{Entity, Node, NodeId, NodeStore, Skolemizer};
+pub use predicate::{
+  Predicate, PredicateId, PredicateStore, RDFS_COMMENT, RDFS_LABEL, RDF_TYPE,
+  SCHEMA_NAME,
+};
+pub use triple::{Triple, TripleId};
 
 // TODO(victor): Generate unique ID for the  Knowledge `GraphScore`. Node ID will be inform of "sg:N4286" while predicate will be inform of "sg:P5245".
@@ -34,7 +34,10 @@ pub trait Connection {}
 /// connections are named `Connection` abd consists of many variants.
 /// Here are some possible connections that can occur among entities
 /// (or nodes) in the graph.
-#[derive(Debug)]
+///
+/// Connections are ordered as declared: `Forward < Shared < Relational <
+/// Multiple`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Connection {
   /// *Forward Connection* connects two nodes together at a time.
   /// This connection might occur multiple times.
@@ -0,0 +1,271 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generation & validation of the ids assigned to nodes, predicates and
+//! triples, e.g. `"sg:N4286"`.
+
+use std::{
+  cmp,
+  convert::TryFrom,
+  sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  error::{Error, ErrorCode},
+  graph::{NodeId, PredicateId, TripleId},
+  sage_err,
+};
+
+/// Prefix of ids minted without an explicit `IdGenerator`.
+pub(crate) const DEFAULT_PREFIX: &str = "sg";
+
+/// Process-wide counters behind every id minted under `DEFAULT_PREFIX`,
+/// whether by `Entity::new`, `Triple::new` or a default `IdGenerator`.
+static NEXT_NODE: AtomicU64 = AtomicU64::new(1);
+static NEXT_PREDICATE: AtomicU64 = AtomicU64::new(1);
+static NEXT_TRIPLE: AtomicU64 = AtomicU64::new(1);
+
+/// Returns the next id of the given `kind` (`N`, `P` or `T`) under
+/// `DEFAULT_PREFIX`.
+pub(crate) fn next_default_id(kind: char) -> String {
+  let n = global_counter(kind).fetch_add(1, Ordering::Relaxed);
+  format!("{}:{}{}", DEFAULT_PREFIX, kind, n)
+}
+
+/// Moves the shared counter of `kind` past `id` if it's a `DEFAULT_PREFIX`
+/// id, so ids reloaded from a persisted graph are never minted again.
+pub(crate) fn reserve_default_id(id: &str, kind: char) {
+  let n = id
+    .strip_prefix(DEFAULT_PREFIX)
+    .and_then(|rest| rest.strip_prefix(':'))
+    .and_then(|rest| rest.strip_prefix(kind))
+    .and_then(|n| n.parse::<u64>().ok());
+  if let Some(n) = n {
+    global_counter(kind).fetch_max(n.saturating_add(1), Ordering::Relaxed);
+  }
+}
+
+fn global_counter(kind: char) -> &'static AtomicU64 {
+  match kind {
+    'N' => &NEXT_NODE,
+    'P' => &NEXT_PREDICATE,
+    _ => &NEXT_TRIPLE,
+  }
+}
+
+/// Checks that `prefix` matches `[a-z][a-z0-9]*`.
+fn is_valid_prefix(prefix: &str) -> bool {
+  let mut chars = prefix.chars();
+  chars.next().is_some_and(|c| c.is_ascii_lowercase())
+    && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+}
+
+/// Checks that `id` matches `[a-z][a-z0-9]*:<kind>\d+`, where `kind` is `N`,
+/// `P` or `T`.
+pub(crate) fn is_valid_id(id: &str, kind: char) -> bool {
+  match id.split_once(':') {
+    Some((prefix, rest)) => {
+      is_valid_prefix(prefix)
+        && rest.strip_prefix(kind).is_some_and(|n| {
+          !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit())
+        })
+    }
+    None => false,
+  }
+}
+
+/// Orders ids by prefix, then numerically by their counter, so that
+/// `"sg:N9" < "sg:N10"`. Ties (e.g. `"sg:N01"` & `"sg:N1"`) fall back to the
+/// ids' text to stay consistent with `Eq`.
+pub(crate) fn cmp_ids(a: &str, b: &str) -> cmp::Ordering {
+  // Splits "<prefix>:<kind><n>" into the prefix & `n` without leading zeros.
+  fn parts(id: &str) -> (&str, &str) {
+    match id.split_once(':') {
+      Some((prefix, rest)) => {
+        let n = rest.get(1..).unwrap_or_default();
+        (prefix, n.trim_start_matches('0'))
+      }
+      None => (id, ""),
+    }
+  }
+  let (prefix_a, n_a) = parts(a);
+  let (prefix_b, n_b) = parts(b);
+  prefix_a
+    .cmp(prefix_b)
+    .then_with(|| n_a.len().cmp(&n_b.len()))
+    .then_with(|| n_a.cmp(n_b))
+    .then_with(|| a.cmp(b))
+}
+
+/// `IdGenerator` mints sequential `NodeId`s, `PredicateId`s & `TripleId`s
+/// under a configurable prefix.
+///
+/// Services sharing logs can use distinct prefixes (`"svc1:N42"` vs
+/// `"svc2:N42"`), and ids with different prefixes never compare equal.
+/// A `NodeStore` owns an `IdGenerator` (see `NodeStore::with_prefix`) and
+/// mints the ids of the nodes it creates through it.
+///
+/// A generator using the default `"sg"` prefix shares its counters with
+/// `Entity::new` & `Triple::new`, so the ids it mints never clash with
+/// theirs. A generator with any other prefix counts on its own.
+///
+/// `IdGenerator` is serializable, and is persisted as part of a `NodeStore`
+/// or `PredicateStore`, so a reloaded graph keeps minting ids with the same
+/// prefix, without reusing any. Deserialization rejects invalid prefixes.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::graph::IdGenerator;
+///
+/// let mut ids = IdGenerator::with_prefix("svc1").unwrap();
+/// assert_eq!(ids.next_node_id().to_string(), "svc1:N1");
+/// assert_eq!(ids.next_node_id().to_string(), "svc1:N2");
+/// assert_eq!(ids.next_triple_id().to_string(), "svc1:T1");
+///
+/// // Reloading the generator continues where it left off.
+/// let saved = sage::json::to_string(&ids).unwrap();
+/// let mut ids: IdGenerator = sage::json::from_str(&saved).unwrap();
+/// assert_eq!(ids.next_node_id().to_string(), "svc1:N3");
+///
+/// assert!(IdGenerator::with_prefix("Svc-1").is_err());
+/// assert!(sage::json::from_str::<IdGenerator>(&saved.replace("svc1", "Svc-1")).is_err());
+/// ```
+///
+/// Default generators never reuse the ids of other entities:
+///
+/// ```rust
+/// use sage::graph::{Entity, IdGenerator, NodeStore};
+///
+/// let mut store = NodeStore::new();
+/// store.insert(Entity::literal(1));
+/// store.insert(Entity::literal(2).with_id(IdGenerator::new().next_node_id()));
+/// assert_eq!(store.len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawIdGenerator")]
+pub struct IdGenerator {
+  prefix: String,
+  next_node: u64,
+  next_predicate: u64,
+  next_triple: u64,
+}
+
+impl IdGenerator {
+  /// Creates an `IdGenerator` using the default `"sg"` prefix, continuing
+  /// the process-wide sequence used by `Entity::new`.
+  pub fn new() -> IdGenerator {
+    IdGenerator {
+      prefix: DEFAULT_PREFIX.to_string(),
+      next_node: 1,
+      next_predicate: 1,
+      next_triple: 1,
+    }
+  }
+
+  /// Creates an `IdGenerator` minting ids under `prefix`, which must match
+  /// `[a-z][a-z0-9]*`.
+  pub fn with_prefix(prefix: &str) -> Result<IdGenerator, Error> {
+    if !is_valid_prefix(prefix) {
+      return Err(sage_err!(
+        ErrorCode::RegexParser,
+        "invalid id prefix {:?}",
+        prefix
+      ));
+    }
+    Ok(IdGenerator {
+      prefix: prefix.to_string(),
+      ..IdGenerator::new()
+    })
+  }
+
+  /// Returns the prefix of the minted ids.
+  pub fn prefix(&self) -> &str {
+    &self.prefix
+  }
+
+  /// Mints the next `NodeId`.
+  pub fn next_node_id(&mut self) -> NodeId {
+    NodeId(self.mint('N', |g| &mut g.next_node))
+  }
+
+  /// Mints the next `PredicateId`.
+  pub fn next_predicate_id(&mut self) -> PredicateId {
+    PredicateId(self.mint('P', |g| &mut g.next_predicate))
+  }
+
+  /// Mints the next `TripleId`.
+  pub fn next_triple_id(&mut self) -> TripleId {
+    TripleId(self.mint('T', |g| &mut g.next_triple))
+  }
+
+  fn mint<F>(&mut self, kind: char, counter: F) -> String
+  where
+    F: FnOnce(&mut IdGenerator) -> &mut u64,
+  {
+    let shared = self.prefix == DEFAULT_PREFIX;
+    let counter = counter(self);
+    let n = if shared {
+      // Skip past any id this generator already minted (e.g. before being
+      // persisted & reloaded) before taking the next shared one.
+      let global = global_counter(kind);
+      global.fetch_max(*counter, Ordering::Relaxed);
+      global.fetch_add(1, Ordering::Relaxed)
+    } else {
+      *counter
+    };
+    *counter = n + 1;
+    format!("{}:{}{}", self.prefix, kind, n)
+  }
+}
+
+/// The serialized form of an `IdGenerator`, checked before use.
+#[derive(Deserialize)]
+struct RawIdGenerator {
+  prefix: String,
+  next_node: u64,
+  next_predicate: u64,
+  next_triple: u64,
+}
+
+impl TryFrom<RawIdGenerator> for IdGenerator {
+  type Error = Error;
+
+  fn try_from(raw: RawIdGenerator) -> Result<Self, Self::Error> {
+    if raw.prefix == DEFAULT_PREFIX {
+      // Keep `Entity::new` & friends from re-minting the reloaded ids.
+      for (kind, next) in [
+        ('N', raw.next_node),
+        ('P', raw.next_predicate),
+        ('T', raw.next_triple),
+      ] {
+        global_counter(kind).fetch_max(next, Ordering::Relaxed);
+      }
+    }
+    Ok(IdGenerator {
+      next_node: raw.next_node,
+      next_predicate: raw.next_predicate,
+      next_triple: raw.next_triple,
+      ..IdGenerator::with_prefix(&raw.prefix)?
+    })
+  }
+}
+
+impl Default for IdGenerator {
+  fn default() -> Self {
+    IdGenerator::new()
+  }
+}
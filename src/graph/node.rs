@@ -14,13 +14,17 @@
 
 #![allow(dead_code)]
 
-use std::{fmt, str::FromStr};
+use std::{cmp, collections::HashMap, convert::TryFrom, fmt, str::FromStr};
 
-use regex::Regex;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::{
-  dtype::{DType, URI},
+  dtype::{DType, Map, IRI, URI},
   error::{Error, ErrorCode},
+  graph::{id, IdGenerator},
+  iri, sage_err,
+  vocab::NamespaceStore,
 };
 
 /*
@@ -39,10 +43,14 @@ use crate::{
 /// `Node` is the crux of a `sage` knowledge graph, in which every *entity*
 /// in the Knowledge Graph is regarded as a `Node` in `sage`.
 ///
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Node {
   /// `Blank` node containing node with empty or null data.
-  Blank,
+  ///
+  /// Blank nodes carry an optional document-local label (the `b0` in `_:b0`)
+  /// so two distinct blank nodes from the same input don't collapse into one.
+  /// `Node::Blank(None)` is an anonymous blank node.
+  Blank(Option<String>),
 
   /// `Schema` node is created from some type of structured data.
   /// For example: wikidata, jsonld, rdf, ntriple or even structs.
@@ -51,6 +59,9 @@ pub enum Node {
   /// `Http` node is used to represent data coming from an external/http source.
   /// And example of such [James Cameron](https://www.wikidata.org/wiki/Q42574)
   /// node gotten from [wikidata](https://www.wikidata.org/wiki/Wikidata:Main_Page).
+  ///
+  /// The IRI is stored as given; prefer [`Node::http`], which validates &
+  /// normalizes it first.
   Http(URI),
 
   /// `Literal` node is used to represent nodes with primitive types
@@ -66,20 +77,116 @@ pub enum Node {
 
 /// Implementation for `Node` enum.
 impl Node {
+  /// Creates the `Node` an IRI string refers to:
+  ///
+  /// - `http://` & `https://` IRIs become `Node::Http`.
+  /// - `_:label` becomes a labelled `Node::Blank` (`_:` alone is anonymous).
+  /// - Anything else becomes a `Node::Literal` string.
+  ///
+  /// Use [`Node::from_iri_with_ns`] to also recognise prefixed schema terms.
+  ///
+  /// ```rust
+  /// # use sage::{dtype::DType, graph::Node};
+  /// #
+  /// assert!(Node::from_iri("https://schema.org/Person").is_http());
+  /// assert_eq!(Node::from_iri("_:b0"), Node::Blank(Some("b0".to_string())));
+  /// assert_eq!(Node::from_iri("_:"), Node::Blank(None));
+  /// assert_eq!(
+  ///   Node::from_iri("John Doe"),
+  ///   Node::Literal(DType::String("John Doe".to_string()))
+  /// );
+  /// ```
+  ///
+  pub fn from_iri(iri: &str) -> Node {
+    if iri.starts_with("http://") || iri.starts_with("https://") {
+      Node::Http(iri.to_string())
+    } else if let Some(label) = iri.strip_prefix("_:") {
+      Node::Blank(Some(label.to_string()).filter(|l| !l.is_empty()))
+    } else {
+      Node::Literal(DType::String(iri.to_string()))
+    }
+  }
+
+  /// Creates a `Node::Http` from a validated & normalized `http(s)` IRI.
+  ///
+  /// Unlike constructing `Node::Http` directly, typos such as a misspelled
+  /// scheme or stray whitespace are rejected.
+  ///
+  /// ```rust
+  /// # use sage::graph::Node;
+  /// #
+  /// assert_eq!(
+  ///   Node::http("HTTPS://Schema.org:443/Person").unwrap(),
+  ///   Node::Http("https://schema.org/Person".to_string())
+  /// );
+  /// assert!(Node::http("htps://schema.org/Person").is_err());
+  /// assert!(Node::http("https://schema.org/John Doe").is_err());
+  /// ```
+  ///
+  pub fn http(iri: &str) -> Result<Node, Error> {
+    let parsed = iri::parse_iri(iri)?;
+    if !parsed.is_http() {
+      return Err(iri::invalid(iri, 0, "expected an http or https IRI"));
+    }
+    Ok(Node::Http(parsed.into_string()))
+  }
+
+  /// Like [`Node::from_iri`], but an IRI that `ns` can resolve (e.g.
+  /// `schema:Person` when `schema:` is registered) becomes a `Node::Schema`.
+  ///
+  /// ```rust
+  /// # use sage::{dtype::DType, graph::Node, vocab::NamespaceStore};
+  /// #
+  /// let mut ns = NamespaceStore::new();
+  /// ns.add_prefix("schema:", "https://schema.org/");
+  ///
+  /// assert!(Node::from_iri_with_ns("schema:Person", &ns).is_schema());
+  /// assert!(Node::from_iri_with_ns("foaf:name", &ns).is_literal());
+  /// assert!(Node::from_iri_with_ns("https://schema.org/Person", &ns).is_http());
+  /// assert!(Node::from_iri_with_ns("_:b0", &ns).is_blank());
+  /// ```
+  ///
+  pub fn from_iri_with_ns(iri: &str, ns: &NamespaceStore) -> Node {
+    match Node::from_iri(iri) {
+      Node::Literal(_) if ns.resolve_iri(iri) != iri => Node::Schema,
+      node => node,
+    }
+  }
+
   /// Check of `Node` is of type `Node::Blank`.
   ///
   /// ```rust
   /// # use sage::{dtype::URI, graph::Node};
   /// #
-  /// let node_type = Node::Blank;
+  /// let node_type = Node::Blank(None);
   /// assert!(node_type.is_blank());
+  /// assert!(Node::Blank(Some("b0".to_string())).is_blank());
   ///
   /// # assert!(!Node::Schema.is_blank());
   /// # assert!(!Node::Http(URI::from("https://schema.org/Person")).is_blank());
   /// ```
   ///
   pub fn is_blank(&self) -> bool {
-    matches!(*self, Node::Blank)
+    matches!(*self, Node::Blank(_))
+  }
+
+  /// Returns the document-local label of a `Node::Blank`, if it has one.
+  ///
+  /// ```rust
+  /// # use sage::graph::Node;
+  /// #
+  /// let b0 = Node::Blank(Some("b0".to_string()));
+  /// assert_eq!(b0.blank_label(), Some("b0"));
+  ///
+  /// assert_eq!(Node::Blank(None).blank_label(), None);
+  /// assert_eq!(Node::Schema.blank_label(), None);
+  /// ```
+  ///
+  pub fn blank_label(&self) -> Option<&str> {
+    match *self {
+      Node::Blank(Some(ref label)) => Some(label),
+      _ => None,
+    }
   }
 
   /// Check if `Node` is of type `Node::Schema`.
@@ -106,7 +213,7 @@ impl Node {
   /// let node_type = Node::Http(URI::from("https://schema.org/Person"));
   /// assert!(node_type.is_http());
   /// #
-  /// # assert!(!Node::Blank.is_http());
+  /// # assert!(!Node::Blank(None).is_http());
   /// # assert!(!Node::Schema.is_http());
   /// ```
   ///
@@ -123,7 +230,7 @@ impl Node {
   /// assert!(node_type.is_literal());
   ///
   /// #
-  /// # assert!(!Node::Blank.is_literal());
+  /// # assert!(!Node::Blank(None).is_literal());
   /// # assert!(!Node::Schema.is_literal());
   /// ```
   ///
@@ -158,7 +265,7 @@ impl Node {
   /// let john : Node = Node::Literal(DType::String("John Doe".to_string()));
   /// assert_eq!(john.get_type(), &john);
   ///
-  /// assert_eq!(Node::Blank.get_type(), &Node::Blank);
+  /// assert_eq!(Node::Blank(None).get_type(), &Node::Blank(None));
   /// ```
   ///
   pub fn get_type(&self) -> &Node {
@@ -166,9 +273,126 @@ impl Node {
   }
 }
 
+/// Formats a `Node` the way N-Triples/Turtle would spell it:
+///
+/// - `Node::Blank(Some(l))` as `_:l`, and an anonymous `Node::Blank(None)`
+///   as Turtle's `[]`.
+/// - `Node::Http(u)` as `<u>`.
+/// - `Node::Literal(v)` as `v` itself.
+/// - `Node::Schema` as `schema`, since it carries no data of its own.
+/// - `Node::Multiple(nodes)` as a Turtle collection, e.g. `(<a> _:b0)`.
+///
+/// ```rust
+/// # use sage::graph::Node;
+/// #
+/// let b0 = Node::Blank(Some("b0".to_string()));
+/// let b1 = Node::Blank(Some("b1".to_string()));
+/// assert_eq!(b0.to_string(), "_:b0");
+/// assert_ne!(b0.to_string(), b1.to_string());
+/// assert_eq!(Node::Blank(None).to_string(), "[]");
+///
+/// let person = Node::Http("https://schema.org/Person".to_string());
+/// assert_eq!(person.to_string(), "<https://schema.org/Person>");
+/// assert_eq!(Node::Literal("John Doe".into()).to_string(), "John Doe");
+/// assert_eq!(Node::Schema.to_string(), "schema");
+/// assert_eq!(
+///   Node::Multiple(vec![person, b0]).to_string(),
+///   "(<https://schema.org/Person> _:b0)"
+/// );
+/// ```
+///
 impl fmt::Display for Node {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "{}", self.get_type())
+    match *self {
+      Node::Blank(Some(ref label)) => write!(f, "_:{}", label),
+      Node::Blank(None) => f.write_str("[]"),
+      Node::Schema => f.write_str("schema"),
+      Node::Http(ref uri) => write!(f, "<{}>", uri),
+      Node::Literal(ref value) => write!(f, "{}", value),
+      Node::Multiple(ref nodes) => {
+        f.write_str("(")?;
+        for (i, node) in nodes.iter().enumerate() {
+          if i > 0 {
+            f.write_str(" ")?;
+          }
+          write!(f, "{}", node)?;
+        }
+        f.write_str(")")
+      }
+    }
+  }
+}
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | Skolemizer
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+ */
+/// `Skolemizer` rewrites blank nodes into [well-known genid IRIs] of the form
+/// `<base>/.well-known/genid/<uuid>` so they can be exported with a stable
+/// identity.
+///
+/// Blank nodes sharing a label are mapped to the same IRI for the lifetime
+/// of the `Skolemizer`, while every anonymous blank node gets a fresh one.
+/// Use one `Skolemizer` per input document.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::graph::{Node, Skolemizer};
+///
+/// let mut sk = Skolemizer::new("https://example.org/");
+///
+/// let b0 = sk.skolemize(&Node::Blank(Some("b0".to_string())));
+/// let b1 = sk.skolemize(&Node::Blank(Some("b1".to_string())));
+///
+/// assert!(b0.is_http());
+/// assert_ne!(b0, b1);
+/// assert_eq!(b0, sk.skolemize(&Node::Blank(Some("b0".to_string()))));
+///
+/// // Anything other than a blank node is returned unchanged.
+/// assert_eq!(sk.skolemize(&Node::Schema), Node::Schema);
+/// ```
+///
+/// [well-known genid IRIs]: https://www.w3.org/TR/rdf11-concepts/#section-skolemization
+#[derive(Debug)]
+pub struct Skolemizer {
+  base: IRI,
+  labels: HashMap<String, IRI>,
+}
+
+impl Skolemizer {
+  /// Creates a new `Skolemizer` minting IRIs under `base_iri`.
+  pub fn new(base_iri: &str) -> Skolemizer {
+    Skolemizer {
+      base: base_iri.trim_end_matches('/').to_string(),
+      labels: HashMap::new(),
+    }
+  }
+
+  /// Returns the skolem IRI for `node` as a `Node::Http`, or a copy of
+  /// `node` itself if it isn't a blank node.
+  pub fn skolemize(&mut self, node: &Node) -> Node {
+    match *node {
+      Node::Blank(Some(ref label)) => {
+        if !self.labels.contains_key(label) {
+          let iri = self.genid();
+          self.labels.insert(label.to_string(), iri);
+        }
+        Node::Http(self.labels[label].clone())
+      }
+      Node::Blank(None) => Node::Http(self.genid()),
+      Node::Multiple(ref nodes) => {
+        Node::Multiple(nodes.iter().map(|n| self.skolemize(n)).collect())
+      }
+      _ => node.clone(),
+    }
+  }
+
+  fn genid(&self) -> IRI {
+    format!("{}/.well-known/genid/{}", self.base, Uuid::new_v4())
   }
 }
 
@@ -181,21 +405,68 @@ impl fmt::Display for Node {
  */
 /// `NodeId` is a unique identifier assigned to every node in the Knowledge Graph.
 ///
-///`NodeId` comes in form of `"sg:N4286"`.
-#[derive(Debug)]
-pub struct NodeId(String);
+///`NodeId` comes in form of `"sg:N4286"`, where the prefix can be any
+/// `[a-z][a-z0-9]*` (see `IdGenerator`).
+///
+/// # Example
+///
+/// ```rust
+/// # use sage::graph::NodeId;
+/// let a: NodeId = "svc1:N42".parse().unwrap();
+/// let b: NodeId = "svc2:N42".parse().unwrap();
+/// assert_ne!(a, b);
+///
+/// assert!("1svc:N42".parse::<NodeId>().is_err());
+/// assert!("SVC:N42".parse::<NodeId>().is_err());
+/// assert!("svc1:T42".parse::<NodeId>().is_err());
+/// assert!("svc1:N".parse::<NodeId>().is_err());
+///
+/// // Ids are ordered by prefix, then numerically.
+/// let n9: NodeId = "sg:N9".parse().unwrap();
+/// let n10: NodeId = "sg:N10".parse().unwrap();
+/// assert!(n9 < n10);
+/// assert!(n10 < a);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String")]
+pub struct NodeId(pub(super) String);
+
+impl NodeId {
+  /// Mints a new `NodeId`, unique for the lifetime of the process.
+  pub(crate) fn generate() -> NodeId {
+    NodeId(id::next_default_id('N'))
+  }
+}
+
+impl Ord for NodeId {
+  fn cmp(&self, other: &NodeId) -> cmp::Ordering {
+    id::cmp_ids(&self.0, &other.0)
+  }
+}
+
+impl PartialOrd for NodeId {
+  fn partial_cmp(&self, other: &NodeId) -> Option<cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl TryFrom<String> for NodeId {
+  type Error = Error;
+
+  fn try_from(s: String) -> Result<Self, Self::Error> {
+    s.parse()
+  }
+}
 
 impl FromStr for NodeId {
   type Err = Error;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    // Match the "sage node" unique ID pattern.
-    let re = Regex::new(r"^sg:N\d+$").unwrap();
-
-    if re.is_match(s) {
+    // Match the "<prefix>:N<n>" unique ID pattern.
+    if id::is_valid_id(s, 'N') {
       Ok(NodeId(String::from(s)))
     } else {
-      Err(Error::syntax(ErrorCode::RegexParser, 189, 9))
+      Err(sage_err!(ErrorCode::RegexParser, "invalid node id {:?}", s))
     }
   }
 }
@@ -218,6 +489,354 @@ impl fmt::Display for NodeId {
   }
 }
 
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | Entity
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+ */
+/// `Entity` represents each *real world object* in the Knowledge Graph.
+///
+/// An `Entity` pairs a unique [`NodeId`] with the [`Node`] variant it holds,
+/// an optional human-readable label & description, and a payload of extra
+/// properties. `Triple`s refer to entities by their `NodeId`, so the same
+/// entity can take part in many triples without being copied.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::graph::Entity;
+///
+/// let avatar = Entity::schema("schema:Movie", "Avatar")
+///   .with_description("2009 film directed by James Cameron");
+///
+/// assert!(avatar.node().is_schema());
+/// assert_eq!(avatar.label(), Some("Avatar"));
+/// assert_eq!(avatar.to_string(), format!("Avatar ({})", avatar.id()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entity {
+  id: NodeId,
+  node: Node,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  label: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  description: Option<String>,
+  #[serde(default, skip_serializing_if = "Map::is_empty")]
+  payload: Map<String, DType>,
+}
+
+impl Entity {
+  /// Creates a new `Entity` holding `node`, with a freshly minted `NodeId`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::{Entity, Node};
+  ///
+  /// let a = Entity::new(Node::Blank(None));
+  /// let b = Entity::new(Node::Blank(None));
+  /// assert_ne!(a.id(), b.id());
+  /// ```
+  pub fn new(node: Node) -> Entity {
+    Entity::new_with_id(NodeId::generate(), node)
+  }
+
+  /// Creates a new `Entity` holding `node` under an existing `id`.
+  fn new_with_id(id: NodeId, node: Node) -> Entity {
+    Entity {
+      id,
+      node,
+      label: None,
+      description: None,
+      payload: Map::new(),
+    }
+  }
+
+  /// Creates a `Node::Literal` entity.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::Entity;
+  ///
+  /// let year = Entity::literal(2009);
+  /// assert!(year.node().is_literal());
+  /// ```
+  pub fn literal<T: Into<DType>>(value: T) -> Entity {
+    Entity::new(Node::Literal(value.into()))
+  }
+
+  /// Creates a `Node::Http` entity for an external resource.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::Entity;
+  ///
+  /// let cameron = Entity::http("https://www.wikidata.org/wiki/Q42574");
+  /// assert!(cameron.node().is_http());
+  /// ```
+  pub fn http<U: Into<URI>>(uri: U) -> Entity {
+    Entity::new(Node::Http(uri.into()))
+  }
+
+  /// Creates a labelled `Node::Schema` entity whose `"@type"` payload entry
+  /// is `type_iri`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{graph::Entity, json};
+  ///
+  /// let avatar = Entity::schema("schema:Movie", "Avatar");
+  /// assert_eq!(avatar.payload()["@type"], json!("schema:Movie"));
+  /// ```
+  pub fn schema(type_iri: &str, label: &str) -> Entity {
+    let mut entity = Entity::new(Node::Schema).with_label(label);
+    entity
+      .payload
+      .insert("@type".to_string(), DType::String(type_iri.to_string()));
+    entity
+  }
+
+  /// Replaces the id of the `Entity`, e.g. with one minted by an
+  /// `IdGenerator`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::graph::{Entity, IdGenerator};
+  /// let mut ids = IdGenerator::with_prefix("svc1").unwrap();
+  /// let entity = Entity::literal(42).with_id(ids.next_node_id());
+  /// assert_eq!(entity.id().to_string(), "svc1:N1");
+  /// ```
+  pub fn with_id(mut self, id: NodeId) -> Entity {
+    self.id = id;
+    self
+  }
+
+  /// Sets the human-readable label of the `Entity`.
+  pub fn with_label(mut self, label: &str) -> Entity {
+    self.label = Some(label.to_string());
+    self
+  }
+
+  /// Sets the description of the `Entity`.
+  pub fn with_description(mut self, description: &str) -> Entity {
+    self.description = Some(description.to_string());
+    self
+  }
+
+  /// Returns the unique id of the `Entity`.
+  pub fn id(&self) -> &NodeId {
+    &self.id
+  }
+
+  /// Returns the `Node` variant held by the `Entity`.
+  pub fn node(&self) -> &Node {
+    &self.node
+  }
+
+  /// Returns the human-readable label of the `Entity`, if any.
+  pub fn label(&self) -> Option<&str> {
+    self.label.as_deref()
+  }
+
+  /// Returns the description of the `Entity`, if any.
+  pub fn description(&self) -> Option<&str> {
+    self.description.as_deref()
+  }
+
+  /// Returns the extra properties attached to the `Entity`.
+  pub fn payload(&self) -> &Map<String, DType> {
+    &self.payload
+  }
+
+  /// Returns a mutable reference to the properties of the `Entity`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{graph::Entity, json};
+  ///
+  /// let mut avatar = Entity::schema("schema:Movie", "Avatar");
+  /// avatar.payload_mut().insert("year".to_string(), json!(2009));
+  /// assert_eq!(avatar.payload()["year"], json!(2009));
+  /// ```
+  pub fn payload_mut(&mut self) -> &mut Map<String, DType> {
+    &mut self.payload
+  }
+
+  /// Sets the payload value at `key`, returning the value it replaces.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::{graph::Entity, json};
+  /// let mut avatar = Entity::schema("schema:Movie", "Avatar");
+  ///
+  /// assert_eq!(avatar.add_payload("year", 2008), None);
+  /// assert_eq!(avatar.add_payload("year", 2009), Some(json!(2008)));
+  /// assert_eq!(avatar.payload()["year"], json!(2009));
+  /// ```
+  pub fn add_payload<V: Into<DType>>(
+    &mut self,
+    key: &str,
+    value: V,
+  ) -> Option<DType> {
+    self.payload.insert(key.to_string(), value.into())
+  }
+
+  /// Like [`Entity::add_payload`], but fails instead of overwriting when
+  /// `key` already holds a different value. Adding the same value again is
+  /// fine.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::{graph::Entity, json};
+  /// let mut avatar = Entity::schema("schema:Movie", "Avatar");
+  ///
+  /// assert!(avatar.try_add_payload("year", 2009).is_ok());
+  /// assert!(avatar.try_add_payload("year", 2009).is_ok());
+  /// assert!(avatar.try_add_payload("year", 2010).is_err());
+  /// assert_eq!(avatar.payload()["year"], json!(2009));
+  /// ```
+  pub fn try_add_payload<V: Into<DType>>(
+    &mut self,
+    key: &str,
+    value: V,
+  ) -> Result<(), Error> {
+    let value = value.into();
+    match self.payload.get(key) {
+      Some(existing) if *existing != value => Err(
+        sage_err!(ErrorCode::Message(
+          format!("payload key {:?} already holds a different value", key)
+            .into_boxed_str()
+        ))
+        .prepend_path(key),
+      ),
+      Some(_) => Ok(()),
+      None => {
+        self.payload.insert(key.to_string(), value);
+        Ok(())
+      }
+    }
+  }
+
+  /// Adds `value` to the payload at `key` without losing earlier values: a
+  /// value already there is turned into an array, and later values are
+  /// appended to it. Useful for multi-valued properties.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::{graph::Entity, json};
+  /// let mut cameron = Entity::schema("schema:Person", "James Cameron");
+  ///
+  /// cameron.add_payload_multi("directed", "Titanic");
+  /// assert_eq!(cameron.payload()["directed"], json!("Titanic"));
+  ///
+  /// cameron.add_payload_multi("directed", "Avatar");
+  /// cameron.add_payload_multi("directed", "Aliens");
+  /// assert_eq!(
+  ///   cameron.payload()["directed"],
+  ///   json!(["Titanic", "Avatar", "Aliens"])
+  /// );
+  /// ```
+  pub fn add_payload_multi<V: Into<DType>>(&mut self, key: &str, value: V) {
+    let value = value.into();
+    match self.payload.get_mut(key) {
+      Some(DType::Array(values)) => values.push(value),
+      Some(existing) => {
+        let first = existing.take();
+        *existing = DType::Array(vec![first, value]);
+      }
+      None => {
+        self.payload.insert(key.to_string(), value);
+      }
+    }
+  }
+
+  /// Returns the payload value at `key` converted to `T`.
+  ///
+  /// Fails if `key` is missing or its value can't be converted; the error's
+  /// [`path`](Error::path) names the key.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::{graph::Entity, json};
+  /// let mut avatar = Entity::schema("schema:Movie", "Avatar");
+  /// avatar.payload_mut().insert("year".to_string(), json!(2009));
+  ///
+  /// assert_eq!(avatar.get_payload_as::<i64>("year").unwrap(), 2009);
+  /// assert!(avatar.get_payload_as::<String>("year").is_err());
+  /// assert!(avatar.get_payload_as::<i64>("rating").is_err());
+  /// ```
+  pub fn get_payload_as<T>(&self, key: &str) -> Result<T, Error>
+  where
+    T: TryFrom<DType, Error = Error>,
+  {
+    let value = self.payload.get(key).cloned().ok_or_else(|| {
+      sage_err!(ErrorCode::Message(
+        format!("missing payload key {:?}", key).into_boxed_str()
+      ))
+    })?;
+    T::try_from(value).map_err(|e| e.prepend_path(key))
+  }
+
+  /// Like [`Entity::get_payload_as`], but returns `T::default()` if `key`
+  /// is missing or its value can't be converted.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::{graph::Entity, json};
+  /// let mut avatar = Entity::schema("schema:Movie", "Avatar");
+  /// avatar.payload_mut().insert("year".to_string(), json!(2009));
+  ///
+  /// assert_eq!(avatar.get_payload_or_default::<i64>("year"), 2009);
+  /// assert_eq!(avatar.get_payload_or_default::<i64>("rating"), 0);
+  /// assert_eq!(avatar.get_payload_or_default::<bool>("year"), false);
+  /// ```
+  pub fn get_payload_or_default<T>(&self, key: &str) -> T
+  where
+    T: TryFrom<DType, Error = Error> + Default,
+  {
+    self.get_payload_as(key).unwrap_or_default()
+  }
+
+  /// Returns an iterator over the payload keys.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use sage::graph::Entity;
+  /// let avatar = Entity::schema("schema:Movie", "Avatar");
+  /// assert_eq!(avatar.payload_keys().collect::<Vec<_>>(), ["@type"]);
+  /// ```
+  pub fn payload_keys(&self) -> impl Iterator<Item = &str> {
+    self.payload.keys().map(String::as_str)
+  }
+
+  /// Returns true if the payload has a value for `key`.
+  pub fn has_payload_key(&self, key: &str) -> bool {
+    self.payload.contains_key(key)
+  }
+}
+
+impl fmt::Display for Entity {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self.label {
+      Some(ref label) => write!(f, "{} ({})", label, self.id),
+      None => fmt::Display::fmt(&self.id, f),
+    }
+  }
+}
+
 /*
  * +----------------------------------------------------------------------+
  * | +------------------------------------------------------------------+ |
@@ -225,10 +844,112 @@ impl fmt::Display for NodeId {
  * | +------------------------------------------------------------------+ |
  * +----------------------------------------------------------------------+
  */
-/// `NodeStore` consist of List of node items.
-#[derive(Default)]
+/// `NodeStore` holds the `Entity`s of a Knowledge Graph, in insertion order,
+/// and looks them up by `NodeId` or label.
+///
+/// Nodes added through [`NodeStore::add`] (or collected from `Node`s) get
+/// ids minted by the store's own [`IdGenerator`], whose prefix is set with
+/// [`NodeStore::with_prefix`].
+///
+/// `NodeStore` is serializable along with its generator, so a reloaded store
+/// keeps its prefix and carries on minting ids where it left off.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::graph::{Node, NodeStore};
+///
+/// let mut nodes = NodeStore::with_prefix("svc1").unwrap();
+/// let john = nodes.add(Node::from_iri("https://example.org/john"));
+///
+/// let saved = sage::json::to_string(&nodes).unwrap();
+/// let mut reloaded: NodeStore = sage::json::from_str(&saved).unwrap();
+/// assert_eq!(reloaded, nodes);
+/// assert!(reloaded.get(&john).unwrap().node().is_http());
+/// assert_eq!(reloaded.add(Node::Blank(None)).to_string(), "svc1:N2");
+/// ```
+///
+/// Reloading a store using the default `"sg"` prefix also keeps
+/// `Entity::new` from minting the ids it already holds:
+///
+/// ```rust
+/// use sage::graph::{Entity, NodeStore};
+///
+/// let saved = r#"{
+///   "entities": [{ "id": "sg:N1", "node": { "Blank": null } }],
+///   "ids": { "prefix": "sg", "next_node": 2, "next_predicate": 1, "next_triple": 1 }
+/// }"#;
+/// let mut nodes: NodeStore = sage::json::from_str(saved).unwrap();
+///
+/// let five = nodes.insert(Entity::literal(5));
+/// assert_ne!(five.to_string(), "sg:N1");
+/// assert_eq!(nodes.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawNodeStore")]
 pub struct NodeStore {
-  nodes: Vec<Node>,
+  entities: Vec<Entity>,
+  #[serde(skip)]
+  index: HashMap<NodeId, usize>,
+  /// Entities by the N-Triples text of their node (see [`node_key`]), in
+  /// insertion order, so `get_or_add` doesn't scan the whole store.
+  #[serde(skip)]
+  by_node: HashMap<String, Vec<usize>>,
+  ids: IdGenerator,
+}
+
+/// The serialized form of a `NodeStore`, whose index is rebuilt from the
+/// entities on load.
+#[derive(Deserialize)]
+struct RawNodeStore {
+  entities: Vec<Entity>,
+  ids: IdGenerator,
+}
+
+impl TryFrom<RawNodeStore> for NodeStore {
+  type Error = Error;
+
+  fn try_from(raw: RawNodeStore) -> Result<Self, Self::Error> {
+    let mut index = HashMap::with_capacity(raw.entities.len());
+    for (i, entity) in raw.entities.iter().enumerate() {
+      id::reserve_default_id(&entity.id.0, 'N');
+      if index.insert(entity.id.clone(), i).is_some() {
+        return Err(sage_err!(ErrorCode::Message(
+          format!("duplicate node {}", entity.id).into_boxed_str()
+        )));
+      }
+    }
+    let mut store = NodeStore {
+      entities: raw.entities,
+      index,
+      by_node: HashMap::new(),
+      ids: raw.ids,
+    };
+    for i in 0..store.entities.len() {
+      store.index_node(i);
+    }
+    Ok(store)
+  }
+}
+
+/// The `by_node` key of `node`: its N-Triples text, or `None` for nodes
+/// holding literal objects, whose text follows the map's iteration order.
+fn node_key(node: &Node) -> Option<String> {
+  fn has_object(value: &DType) -> bool {
+    match value {
+      DType::Object(_) => true,
+      DType::Array(values) => values.iter().any(has_object),
+      _ => false,
+    }
+  }
+  fn is_canonical(node: &Node) -> bool {
+    match node {
+      Node::Literal(value) => !has_object(value),
+      Node::Multiple(nodes) => nodes.iter().all(is_canonical),
+      _ => true,
+    }
+  }
+  is_canonical(node).then(|| node.to_string())
 }
 
 impl NodeStore {
@@ -243,14 +964,193 @@ impl NodeStore {
   /// assert_eq!(nodes.len(), 0);
   /// ```
   pub fn new() -> NodeStore {
-    NodeStore { nodes: Vec::new() }
+    NodeStore::with_id_generator(IdGenerator::new())
   }
 
-  pub fn nodes(&self) -> &[Node] {
-    &self.nodes
+  /// Creates an empty `NodeStore` minting node ids under `prefix`, which
+  /// must match `[a-z][a-z0-9]*`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::{Node, NodeStore};
+  ///
+  /// let mut nodes = NodeStore::with_prefix("svc1").unwrap();
+  /// let id = nodes.add(Node::Blank(None));
+  /// assert_eq!(id.to_string(), "svc1:N1");
+  ///
+  /// assert!(NodeStore::with_prefix("Svc-1").is_err());
+  /// ```
+  pub fn with_prefix(prefix: &str) -> Result<NodeStore, Error> {
+    Ok(NodeStore::with_id_generator(IdGenerator::with_prefix(
+      prefix,
+    )?))
   }
 
-  /// Checks if the `NodeStore` is empty.
+  /// Creates an empty `NodeStore` minting node ids through `ids`, e.g. one
+  /// shared with another store through [`NodeStore::id_generator`]. A
+  /// serialized `NodeStore` already brings its own generator back.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::{IdGenerator, Node, NodeStore};
+  ///
+  /// let mut nodes = NodeStore::with_prefix("svc1").unwrap();
+  /// nodes.add(Node::Blank(None));
+  /// let saved = sage::json::to_string(nodes.id_generator()).unwrap();
+  ///
+  /// let ids: IdGenerator = sage::json::from_str(&saved).unwrap();
+  /// let mut reloaded = NodeStore::with_id_generator(ids);
+  /// assert_eq!(reloaded.add(Node::Blank(None)).to_string(), "svc1:N2");
+  /// ```
+  pub fn with_id_generator(ids: IdGenerator) -> NodeStore {
+    NodeStore {
+      entities: Vec::new(),
+      index: HashMap::new(),
+      by_node: HashMap::new(),
+      ids,
+    }
+  }
+
+  /// Returns the `IdGenerator` minting the store's node ids.
+  pub fn id_generator(&self) -> &IdGenerator {
+    &self.ids
+  }
+
+  /// Wraps `node` in a new `Entity` with an id minted by the store, adds it
+  /// and returns its id.
+  pub fn add(&mut self, node: Node) -> NodeId {
+    let id = self.ids.next_node_id();
+    self.insert(Entity::new_with_id(id, node))
+  }
+
+  /// Returns the entities in the store, in insertion order.
+  pub fn entities(&self) -> &[Entity] {
+    &self.entities
+  }
+
+  /// Returns an iterator over the entities in the store, in insertion order.
+  pub fn iter(&self) -> std::slice::Iter<'_, Entity> {
+    self.entities.iter()
+  }
+
+  /// Adds `entity` to the store and returns its id. An entity with the same
+  /// id already in the store is replaced.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::{Entity, NodeStore};
+  ///
+  /// let mut nodes = NodeStore::new();
+  /// let id = nodes.insert(Entity::schema("schema:Movie", "Avatar"));
+  ///
+  /// assert_eq!(nodes.get(&id).unwrap().label(), Some("Avatar"));
+  /// assert_eq!(nodes.get_by_label("Avatar").unwrap().id(), &id);
+  /// assert!(nodes.get_by_label("Titanic").is_none());
+  /// ```
+  pub fn insert(&mut self, entity: Entity) -> NodeId {
+    let id = entity.id.clone();
+    match self.index.get(&id) {
+      Some(&i) => {
+        self.unindex_node(i);
+        self.entities[i] = entity;
+        self.index_node(i);
+      }
+      None => {
+        let i = self.entities.len();
+        self.index.insert(id.clone(), i);
+        self.entities.push(entity);
+        self.index_node(i);
+      }
+    }
+    id
+  }
+
+  /// Returns the id of the first entity, in insertion order, holding `node`,
+  /// adding `node` with [`NodeStore::add`] if there is none.
+  ///
+  /// Nodes are looked up by their N-Triples text, so this doesn't scan the
+  /// store, except for literal objects (e.g. `Node::Literal(json!({..}))`).
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::{Node, NodeStore};
+  ///
+  /// let mut nodes = NodeStore::new();
+  /// let a = nodes.get_or_add(Node::from_iri("https://example.org/john"));
+  /// let b = nodes.get_or_add(Node::from_iri("https://example.org/john"));
+  /// assert_eq!(a, b);
+  /// assert_eq!(nodes.len(), 1);
+  ///
+  /// // A literal spelled like the IRI is still a different node.
+  /// let c = nodes.get_or_add(Node::from_iri("<https://example.org/john>"));
+  /// assert_ne!(a, c);
+  /// assert_eq!(nodes.len(), 2);
+  /// ```
+  pub fn get_or_add(&mut self, node: Node) -> NodeId {
+    let found = match node_key(&node) {
+      Some(key) => self.by_node.get(&key).and_then(|slots| {
+        slots
+          .iter()
+          .map(|&i| &self.entities[i])
+          .find(|e| e.node() == &node)
+      }),
+      None => self.entities.iter().find(|e| e.node() == &node),
+    };
+    match found {
+      Some(entity) => entity.id.clone(),
+      None => self.add(node),
+    }
+  }
+
+  /// Adds the entity at `i` to `by_node`, keeping each slot list sorted.
+  fn index_node(&mut self, i: usize) {
+    if let Some(key) = node_key(self.entities[i].node()) {
+      let slots = self.by_node.entry(key).or_default();
+      let at = slots.partition_point(|&j| j < i);
+      slots.insert(at, i);
+    }
+  }
+
+  /// Removes the entity at `i` from `by_node`.
+  fn unindex_node(&mut self, i: usize) {
+    if let Some(key) = node_key(self.entities[i].node()) {
+      if let Some(slots) = self.by_node.get_mut(&key) {
+        slots.retain(|&j| j != i);
+        if slots.is_empty() {
+          self.by_node.remove(&key);
+        }
+      }
+    }
+  }
+
+  /// Returns the entity with the given id.
+  pub fn get(&self, id: &NodeId) -> Option<&Entity> {
+    self.index.get(id).map(|&i| &self.entities[i])
+  }
+
+  /// Returns a mutable reference to the entity with the given id.
+  pub fn get_mut(&mut self, id: &NodeId) -> Option<&mut Entity> {
+    match self.index.get(id) {
+      Some(&i) => Some(&mut self.entities[i]),
+      None => None,
+    }
+  }
+
+  /// Returns the first entity, in insertion order, labelled `label`.
+  pub fn get_by_label(&self, label: &str) -> Option<&Entity> {
+    self.entities.iter().find(|e| e.label() == Some(label))
+  }
+
+  /// Checks if the store holds an entity with the given id.
+  pub fn contains(&self, id: &NodeId) -> bool {
+    self.index.contains_key(id)
+  }
+
+  /// Returns the length of the nodes in the store.
   ///
   /// # Example
   ///
@@ -258,13 +1158,13 @@ impl NodeStore {
   /// use sage::graph::NodeStore;
   ///
   /// let nodes = NodeStore::new();
-  /// assert_eq!(nodes.is_empty(), true);
+  /// assert_eq!(nodes.len(), 0);
   /// ```
   pub fn len(&self) -> usize {
-    self.nodes.len()
+    self.entities.len()
   }
 
-  /// Returns the length of the nodes in the store.
+  /// Checks if the `NodeStore` is empty.
   ///
   /// # Example
   ///
@@ -272,70 +1172,90 @@ impl NodeStore {
   /// use sage::graph::NodeStore;
   ///
   /// let nodes = NodeStore::new();
-  /// assert_eq!(nodes.len(), 0);
+  /// assert_eq!(nodes.is_empty(), true);
   /// ```
   pub fn is_empty(&self) -> bool {
     self.len() == 0
   }
 }
 
-/*
- * +----------------------------------------------------------------------+
- * | +------------------------------------------------------------------+ |
- * | | NodeImpl
- * | +------------------------------------------------------------------+ |
- * +----------------------------------------------------------------------+
- */
-#[derive(Debug)]
-struct NodeImpl {
-  /// Node ID should be inform of "sg:N4236".
-  id: NodeId,
-  // `Node` describes the variant of node the current node is.
-  node_type: Node,
-}
-
-impl NodeImpl {
-  fn new(node_type: Node) -> NodeImpl {
-    NodeImpl {
-      id: NodeId("sg:N".to_string()).next().unwrap(),
-      node_type,
-    }
+impl FromIterator<Entity> for NodeStore {
+  fn from_iter<I: IntoIterator<Item = Entity>>(iter: I) -> Self {
+    let mut store = NodeStore::new();
+    store.extend(iter);
+    store
   }
+}
 
-  /// Return the id of the current `Node`.
-  fn id(&self) -> &str {
-    &self.id.0
+/// Collects `Node`s into a `NodeStore`, wrapping each in a new `Entity`.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::graph::{Node, NodeStore};
+///
+/// let store: NodeStore = ["Rust", "Java", "Python"]
+///   .into_iter()
+///   .map(|lang| Node::Literal(lang.into()))
+///   .collect();
+///
+/// assert_eq!(store.len(), 3);
+/// assert!(store.into_iter().all(|e| e.node().is_literal()));
+/// ```
+impl FromIterator<Node> for NodeStore {
+  fn from_iter<I: IntoIterator<Item = Node>>(iter: I) -> Self {
+    let mut store = NodeStore::new();
+    store.extend(iter);
+    store
   }
 }
 
-impl Default for NodeImpl {
-  fn default() -> Self {
-    Self::new(Node::Blank)
+impl Extend<Entity> for NodeStore {
+  fn extend<I: IntoIterator<Item = Entity>>(&mut self, iter: I) {
+    for entity in iter {
+      self.insert(entity);
+    }
   }
 }
 
-/*
-/// `Entity` represents each *real world object* in the Knowledge Graph.
-/// There are different variants of nodes which can be found in
-/// `Node` enum.
+/// Extends a `NodeStore` with `Node`s, wrapping each in a new `Entity` with
+/// an id minted by the store.
 ///
-/// `Node` is the crux of a `sage` knowledge graph, in which every *"entity"*
-/// in the Knowledge Graph is regarded as a `Node` in `sage`.
+/// # Example
 ///
-/// As for the implementation of a `Node`, it has a private node implementation
-/// which is only exposed through the `Node::node` and it is boxed for memory
-/// management purposes. Higher level APIs are provided to work with `Node` as
-/// effectively and efficiently as possible.
-#[derive(Debug, Default)]
-pub struct Entity {
-  node: Box<NodeImpl>,
+/// ```rust
+/// use sage::graph::{Entity, Node, NodeStore};
+///
+/// let mut store: NodeStore = vec![Entity::schema("schema:Movie", "Avatar")]
+///   .into_iter()
+///   .collect();
+/// store.extend(vec![Node::Blank(None), Node::Schema]);
+///
+/// let labels: Vec<_> = store.iter().map(|e| e.label()).collect();
+/// assert_eq!(labels, vec![Some("Avatar"), None, None]);
+/// ```
+impl Extend<Node> for NodeStore {
+  fn extend<I: IntoIterator<Item = Node>>(&mut self, iter: I) {
+    for node in iter {
+      self.add(node);
+    }
+  }
 }
 
-impl Entity {
-  pub fn new(dtype: Node) -> Entity {
-    Entity {
-      node: Box::new(NodeImpl::new(dtype)),
-    }
+impl IntoIterator for NodeStore {
+  type Item = Entity;
+  type IntoIter = std::vec::IntoIter<Entity>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.entities.into_iter()
+  }
+}
+
+impl<'a> IntoIterator for &'a NodeStore {
+  type Item = &'a Entity;
+  type IntoIter = std::slice::Iter<'a, Entity>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.entities.iter()
   }
 }
-*/
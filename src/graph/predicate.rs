@@ -14,15 +14,22 @@
 
 #![allow(dead_code)]
 
-use std::{fmt, str::FromStr};
+use std::{
+  collections::HashMap, convert::TryFrom, fmt, str::FromStr, sync::LazyLock,
+};
+
+use serde::{Deserialize, Serialize};
 
 use crate::{
+  dtype::IRI,
   error::{Error, ErrorCode},
-  vocab::Namespace,
+  graph::id::{self, IdGenerator},
+  sage_err,
+  vocab::{
+    Namespace, NamespaceStore, RdfVocab, RdfsVocab, SchemaVocab, Vocabulary,
+  },
 };
 
-use regex::Regex;
-
 /// Predicate is the actual data contained when two `Node`s are connected through some `ConnectionType`.
 pub trait Pred<T> {}
 
@@ -39,20 +46,31 @@ pub trait Pred<T> {}
 /// `PredicateId` is a unique identifier assigned to every node in the Knowledge Graph.
 ///
 ///`PredicateId` comes in form of `"sg:P8080"`.
-#[derive(Debug)]
-pub struct PredicateId(String);
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String")]
+pub struct PredicateId(pub(super) String);
+
+impl TryFrom<String> for PredicateId {
+  type Error = Error;
+
+  fn try_from(s: String) -> Result<Self, Self::Error> {
+    s.parse()
+  }
+}
 
 impl FromStr for PredicateId {
   type Err = Error;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    // Match the "sage predicate" unique ID pattern.
-    let re = Regex::new(r"^sg:P\d+$").unwrap();
-
-    if re.is_match(s) {
+    // Match the "<prefix>:P<n>" unique ID pattern.
+    if id::is_valid_id(s, 'P') {
       Ok(PredicateId(String::from(s)))
     } else {
-      Err(Error::syntax(ErrorCode::RegexParser, 37, 8))
+      Err(sage_err!(
+        ErrorCode::RegexParser,
+        "invalid predicate id {:?}",
+        s
+      ))
     }
   }
 }
@@ -75,7 +93,7 @@ impl fmt::Display for PredicateId {
   }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Predicate {
   /// *Literal predicate* describes the connection between two `Node`s
   /// in form of a string slice (`&str`) or `String`.
@@ -128,6 +146,71 @@ impl Predicate {
     matches!(*self, Predicate::Uri(_))
   }
 
+  /// Creates a `Predicate::Uri` for the term `local` of the vocabulary `ns`,
+  /// e.g. `schema:director` from the `schema:` namespace.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::Predicate;
+  /// use sage::vocab::Namespace;
+  ///
+  /// let schema = Namespace::try_new("schema:", "https://schema.org/").unwrap();
+  /// let director = Predicate::from_namespace(&schema, "director");
+  ///
+  /// assert_eq!(
+  ///   director,
+  ///   Predicate::Uri(Namespace::try_new(
+  ///     "schema:director",
+  ///     "https://schema.org/director"
+  ///   ).unwrap())
+  /// );
+  /// ```
+  ///
+  pub fn from_namespace(ns: &Namespace, local: &str) -> Predicate {
+    Predicate::Uri(Namespace::unchecked(
+      &format!("{}{}", ns.prefix(), local),
+      &format!("{}{}", ns.full(), local),
+    ))
+  }
+
+  /// Creates a `Predicate::Uri` from a fully-expanded IRI. IRIs in the RDF,
+  /// RDFS or Schema.org vocabularies get their compact prefix; any other IRI
+  /// gets a blank one.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::{Predicate, RDF_TYPE};
+  /// use sage::vocab::Namespace;
+  ///
+  /// assert_eq!(
+  ///   Predicate::from_uri("http://www.w3.org/1999/02/22-rdf-syntax-ns#type"),
+  ///   *RDF_TYPE
+  /// );
+  /// assert_eq!(
+  ///   Predicate::from_uri("http://xmlns.com/foaf/0.1/name"),
+  ///   Predicate::Uri(Namespace::try_new("", "http://xmlns.com/foaf/0.1/name").unwrap())
+  /// );
+  /// ```
+  ///
+  pub fn from_uri(full_iri: &str) -> Predicate {
+    let vocabularies = [
+      (RdfVocab::prefix(), RdfVocab::full()),
+      (RdfsVocab::prefix(), RdfsVocab::full()),
+      (SchemaVocab::prefix(), SchemaVocab::full()),
+    ];
+    for (prefix, full) in vocabularies {
+      if let Some(local) = full_iri.strip_prefix(full.as_str()) {
+        if !local.is_empty() {
+          let ns = Namespace::unchecked(&prefix, &full);
+          return Predicate::from_namespace(&ns, local);
+        }
+      }
+    }
+    Predicate::Uri(Namespace::unchecked("", full_iri))
+  }
+
   /// Returns the `Predicate` variant.
   ///
   /// # Example
@@ -146,7 +229,10 @@ impl Predicate {
 
 impl fmt::Display for Predicate {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "{}", self.get_type())
+    match self {
+      Predicate::Literal(s) => write!(f, "{}", s),
+      Predicate::Uri(ns) => write!(f, "{}", ns.full()),
+    }
   }
 }
 
@@ -163,3 +249,280 @@ impl PredicateImpl {
     }
   }
 }
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | PredicateStore
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+ */
+/// `PredicateStore` interns the `Predicate`s of a Knowledge Graph, so each
+/// distinct predicate gets exactly one `PredicateId`, however often it's used.
+///
+/// Predicates are compared by their IRI: a `Predicate::Uri` by its full IRI,
+/// and a `Predicate::Literal` by its text, expanded through the attached
+/// `NamespaceStore` (if any) when it's a prefixed name such as `schema:name`.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::graph::{Predicate, PredicateStore};
+/// use sage::vocab::{Namespace, NamespaceStore};
+///
+/// let mut ns = NamespaceStore::new();
+/// ns.add_prefix("schema:", "https://schema.org/");
+/// let mut predicates = PredicateStore::with_namespaces(ns);
+///
+/// let literal = predicates
+///   .get_or_create(Predicate::Literal("schema:director".to_string()));
+/// let uri = predicates.get_or_create(Predicate::Uri(Namespace::try_new(
+///   "schema:director",
+///   "https://schema.org/director",
+/// ).unwrap()));
+///
+/// assert_eq!(literal, uri);
+/// assert_eq!(predicates.len(), 1);
+/// assert_eq!(predicates.usage_count(&literal), 2);
+/// ```
+///
+/// `PredicateStore` is serializable, so a persisted graph keeps the ids of
+/// its predicates and reloads minting new ones where it left off:
+///
+/// ```rust
+/// use sage::graph::{IdGenerator, Predicate, PredicateStore};
+///
+/// let ids = IdGenerator::with_prefix("svc1").unwrap();
+/// let mut predicates = PredicateStore::with_id_generator(ids);
+/// let directed = predicates.get_or_create(Predicate::Literal("directed".into()));
+/// assert_eq!(directed.to_string(), "svc1:P1");
+///
+/// let saved = sage::json::to_string(&predicates).unwrap();
+/// let mut reloaded: PredicateStore = sage::json::from_str(&saved).unwrap();
+/// assert_eq!(reloaded, predicates);
+///
+/// assert_eq!(reloaded.id_of(&Predicate::Literal("directed".into())), Some(&directed));
+/// assert_eq!(reloaded.usage_count(&directed), 1);
+///
+/// let starred = reloaded.get_or_create(Predicate::Literal("starred".into()));
+/// assert_eq!(starred.to_string(), "svc1:P2");
+/// assert_eq!(reloaded.get(&starred), Some(&Predicate::Literal("starred".into())));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawPredicateStore")]
+pub struct PredicateStore {
+  ids: IdGenerator,
+  namespaces: Option<NamespaceStore>,
+  predicates: Vec<InternedPredicate>,
+  #[serde(skip)]
+  by_key: HashMap<IRI, usize>,
+  #[serde(skip)]
+  by_id: HashMap<PredicateId, usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct InternedPredicate {
+  id: PredicateId,
+  predicate: Predicate,
+  uses: usize,
+}
+
+impl PredicateStore {
+  /// Creates an empty `PredicateStore` with no namespaces attached.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::PredicateStore;
+  ///
+  /// let predicates = PredicateStore::new();
+  /// assert!(predicates.is_empty());
+  /// ```
+  pub fn new() -> PredicateStore {
+    PredicateStore::default()
+  }
+
+  /// Creates an empty `PredicateStore` which expands prefixed literal
+  /// predicates through `namespaces`.
+  pub fn with_namespaces(namespaces: NamespaceStore) -> PredicateStore {
+    PredicateStore {
+      namespaces: Some(namespaces),
+      ..PredicateStore::default()
+    }
+  }
+
+  /// Creates an empty `PredicateStore` minting predicate ids through `ids`,
+  /// e.g. one created with [`IdGenerator::with_prefix`].
+  pub fn with_id_generator(ids: IdGenerator) -> PredicateStore {
+    PredicateStore {
+      ids,
+      ..PredicateStore::default()
+    }
+  }
+
+  /// Returns the id of `predicate`, minting one the first time it's seen.
+  /// Every call counts as a use of the predicate.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::{Predicate, PredicateStore};
+  ///
+  /// let mut predicates = PredicateStore::new();
+  /// let directed = predicates.get_or_create(Predicate::Literal("directed".into()));
+  /// let starred = predicates.get_or_create(Predicate::Literal("starred".into()));
+  ///
+  /// assert_ne!(directed, starred);
+  /// assert_eq!(
+  ///   predicates.get_or_create(Predicate::Literal("directed".into())),
+  ///   directed
+  /// );
+  /// assert_eq!(predicates.usage_count(&directed), 2);
+  /// ```
+  pub fn get_or_create(&mut self, predicate: Predicate) -> PredicateId {
+    let key = self.key(&predicate);
+    if let Some(&i) = self.by_key.get(&key) {
+      self.predicates[i].uses += 1;
+      return self.predicates[i].id.clone();
+    }
+
+    let id = self.ids.next_predicate_id();
+    let i = self.predicates.len();
+    self.by_key.insert(key, i);
+    self.by_id.insert(id.clone(), i);
+    self.predicates.push(InternedPredicate {
+      id: id.clone(),
+      predicate,
+      uses: 1,
+    });
+    id
+  }
+
+  /// Returns the id of `predicate`, if it has been interned.
+  pub fn id_of(&self, predicate: &Predicate) -> Option<&PredicateId> {
+    self
+      .by_key
+      .get(&self.key(predicate))
+      .map(|&i| &self.predicates[i].id)
+  }
+
+  /// Returns the predicate interned as `id`.
+  pub fn get(&self, id: &PredicateId) -> Option<&Predicate> {
+    self.by_id.get(id).map(|&i| &self.predicates[i].predicate)
+  }
+
+  /// Returns how many times the predicate with `id` has been passed to
+  /// [`PredicateStore::get_or_create`], or `0` if it's unknown.
+  pub fn usage_count(&self, id: &PredicateId) -> usize {
+    self.by_id.get(id).map_or(0, |&i| self.predicates[i].uses)
+  }
+
+  /// Returns an iterator over the interned predicates & their ids, in the
+  /// order they were first seen.
+  pub fn iter(&self) -> impl Iterator<Item = (&PredicateId, &Predicate)> {
+    self.predicates.iter().map(|p| (&p.id, &p.predicate))
+  }
+
+  /// Returns the number of distinct predicates in the store.
+  pub fn len(&self) -> usize {
+    self.predicates.len()
+  }
+
+  /// Checks if the `PredicateStore` is empty.
+  pub fn is_empty(&self) -> bool {
+    self.predicates.is_empty()
+  }
+
+  /// The IRI `predicate` is interned under.
+  fn key(&self, predicate: &Predicate) -> IRI {
+    match (predicate, &self.namespaces) {
+      (Predicate::Uri(ns), _) => ns.full().to_string(),
+      (Predicate::Literal(s), Some(namespaces)) => namespaces.resolve_iri(s),
+      (Predicate::Literal(s), None) => s.clone(),
+    }
+  }
+}
+
+/// The serialized form of a `PredicateStore`, whose lookup tables are
+/// rebuilt from the predicate list on load.
+#[derive(Deserialize)]
+struct RawPredicateStore {
+  ids: IdGenerator,
+  namespaces: Option<NamespaceStore>,
+  predicates: Vec<InternedPredicate>,
+}
+
+impl TryFrom<RawPredicateStore> for PredicateStore {
+  type Error = Error;
+
+  fn try_from(raw: RawPredicateStore) -> Result<Self, Self::Error> {
+    let mut store = PredicateStore {
+      ids: raw.ids,
+      namespaces: raw.namespaces,
+      ..PredicateStore::default()
+    };
+    for (i, interned) in raw.predicates.iter().enumerate() {
+      id::reserve_default_id(&interned.id.0, 'P');
+      let key = store.key(&interned.predicate);
+      if store.by_key.insert(key, i).is_some()
+        || store.by_id.insert(interned.id.clone(), i).is_some()
+      {
+        return Err(sage_err!(ErrorCode::Message(
+          format!("duplicate predicate {}", interned.id).into_boxed_str()
+        )));
+      }
+    }
+    store.predicates = raw.predicates;
+    Ok(store)
+  }
+}
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | Standard predicates.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+ */
+// `Namespace` owns `String`s, so these can't be `const`; they are built on
+// first use instead.
+
+/// `rdf:type` — the subject is an instance of a class.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::graph::{Predicate, RDF_TYPE};
+///
+/// match *RDF_TYPE {
+///   Predicate::Uri(ref ns) => {
+///     assert_eq!(ns.prefix(), "rdf:type");
+///     assert_eq!(ns.full(), "http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
+///   }
+///   Predicate::Literal(_) => unreachable!(),
+/// }
+/// ```
+pub static RDF_TYPE: LazyLock<Predicate> =
+  LazyLock::new(|| vocab_term::<RdfVocab>("type"));
+
+/// `rdfs:label` — a human-readable name for the subject.
+pub static RDFS_LABEL: LazyLock<Predicate> =
+  LazyLock::new(|| vocab_term::<RdfsVocab>("label"));
+
+/// `rdfs:comment` — a description of the subject resource.
+pub static RDFS_COMMENT: LazyLock<Predicate> =
+  LazyLock::new(|| vocab_term::<RdfsVocab>("comment"));
+
+/// `schema:name` — the name of the item.
+pub static SCHEMA_NAME: LazyLock<Predicate> =
+  LazyLock::new(|| vocab_term::<SchemaVocab>("name"));
+
+fn vocab_term<V>(local: &str) -> Predicate
+where
+  V: Vocabulary<Prefix = IRI, Full = IRI>,
+{
+  Predicate::from_namespace(
+    &Namespace::unchecked(&V::prefix(), &V::full()),
+    local,
+  )
+}
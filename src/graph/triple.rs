@@ -14,15 +14,14 @@
 
 #![allow(dead_code)]
 
-use std::{fmt, str::FromStr};
+use std::{cmp, fmt, str::FromStr};
 
 use crate::{
   error::{Error, ErrorCode},
-  graph::*,
+  graph::{id, *},
+  sage_err,
 };
 
-use regex::Regex;
-
 /*
  * +----------------------------------------------------------------------+
  * | +------------------------------------------------------------------+ |
@@ -30,12 +29,30 @@ use regex::Regex;
  * | +------------------------------------------------------------------+ |
  * +----------------------------------------------------------------------+
  */
-#[derive(Debug, Eq)]
-pub struct TripleId(String);
+/// `TripleId` is a unique identifier assigned to every triple in the
+/// Knowledge Graph.
+///
+/// `TripleId` comes in form of `"sg:T4286"`. Like `NodeId`s, `TripleId`s are
+/// ordered by prefix, then numerically.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TripleId(pub(super) String);
+
+impl TripleId {
+  /// Mints a new `TripleId`, unique for the lifetime of the process.
+  fn generate() -> TripleId {
+    TripleId(id::next_default_id('T'))
+  }
+}
+
+impl Ord for TripleId {
+  fn cmp(&self, other: &TripleId) -> cmp::Ordering {
+    id::cmp_ids(&self.0, &other.0)
+  }
+}
 
-impl PartialEq for TripleId {
-  fn eq(&self, other: &TripleId) -> bool {
-    self.0 == other.0
+impl PartialOrd for TripleId {
+  fn partial_cmp(&self, other: &TripleId) -> Option<cmp::Ordering> {
+    Some(self.cmp(other))
   }
 }
 
@@ -43,13 +60,15 @@ impl FromStr for TripleId {
   type Err = Error;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    // Match the "sage triple" unique ID pattern.
-    let re = Regex::new(r"^sg:T\d+$").unwrap();
-
-    if re.is_match(s) {
+    // Match the "<prefix>:T<n>" unique ID pattern.
+    if id::is_valid_id(s, 'T') {
       Ok(TripleId(String::from(s)))
     } else {
-      Err(Error::syntax(ErrorCode::RegexParser, 22, 27))
+      Err(sage_err!(
+        ErrorCode::RegexParser,
+        "invalid triple id {:?}",
+        s
+      ))
     }
   }
 }
@@ -79,25 +98,76 @@ impl fmt::Display for TripleId {
  * | +------------------------------------------------------------------+ |
  * +----------------------------------------------------------------------+
  */
+/// `Triple` connects a source `Entity` to a destination `Entity` through a
+/// `Predicate`.
+///
+/// A `Triple` refers to its entities by `NodeId` rather than owning them; the
+/// entities themselves live in a `NodeStore`.
+///
+/// Two triples are equal when they have the same `TripleId`. Cloning a
+/// `Triple` keeps its `TripleId`, so a clone is equal to the original: it is
+/// the same statement, not a new one.
+#[derive(Clone, Debug)]
 pub struct Triple {
   id: TripleId,
-  source: Node,
+  source: NodeId,
   predicate: Predicate,
-  destination: Node,
+  destination: NodeId,
   connection: Connection,
 }
 
 impl Triple {
-  pub fn new() -> Triple {
+  /// Creates a new `Triple` connecting `source` to `destination`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::{Connection, Entity, NodeStore, Predicate, Triple};
+  ///
+  /// let mut store = NodeStore::new();
+  /// let cameron = store.insert(Entity::schema("schema:Person", "James Cameron"));
+  /// let avatar = store.insert(Entity::schema("schema:Movie", "Avatar"));
+  ///
+  /// let directed = Triple::new(
+  ///   &cameron,
+  ///   Predicate::Literal("directed".to_string()),
+  ///   &avatar,
+  ///   Connection::Forward,
+  /// );
+  ///
+  /// let movie = store.get(directed.destination()).unwrap();
+  /// assert_eq!(movie.label(), Some("Avatar"));
+  /// ```
+  pub fn new(
+    source: &NodeId,
+    predicate: Predicate,
+    destination: &NodeId,
+    connection: Connection,
+  ) -> Triple {
     Triple {
-      id: TripleId("sg:T".to_string()).next().unwrap(),
-      source: Node::Blank,
-      predicate: Predicate::Literal("".to_string()),
-      destination: Node::Blank,
-      connection: Connection::Forward,
+      id: TripleId::generate(),
+      source: source.clone(),
+      predicate,
+      destination: destination.clone(),
+      connection,
     }
   }
 
+  /// Returns the id of the source entity.
+  pub fn source(&self) -> &NodeId {
+    &self.source
+  }
+
+  /// Returns the `Predicate` connecting source & destination.
+  pub fn predicate(&self) -> &Predicate {
+    &self.predicate
+  }
+
+  /// Returns the id of the destination entity.
+  pub fn destination(&self) -> &NodeId {
+    &self.destination
+  }
+
   #[doc(hidden)]
   pub fn id(&self) -> &TripleId {
     &self.id
@@ -107,6 +177,59 @@ impl Triple {
   pub fn connection(&self) -> &Connection {
     &self.connection
   }
+
+  /// Orders triples by `Connection` (`Forward` first), then by source id,
+  /// then by predicate, and finally by `TripleId` so that only equal
+  /// triples compare `Equal`. This is the order used by `PartialOrd`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::graph::{Connection, Entity, Predicate, Triple};
+  ///
+  /// let (john, jane) = (Entity::literal("John"), Entity::literal("Jane"));
+  /// let knows = || Predicate::Literal("knows".to_string());
+  /// let likes = || Predicate::Literal("likes".to_string());
+  ///
+  /// let mut triples = vec![
+  ///   Triple::new(john.id(), knows(), jane.id(), Connection::Shared),
+  ///   Triple::new(john.id(), likes(), jane.id(), Connection::Forward),
+  ///   Triple::new(john.id(), knows(), jane.id(), Connection::Forward),
+  /// ];
+  /// triples.sort_by(Triple::cmp_by_connection);
+  ///
+  /// let order: Vec<_> = triples
+  ///   .iter()
+  ///   .map(|t| (*t.connection(), t.predicate().to_string()))
+  ///   .collect();
+  /// assert_eq!(
+  ///   order,
+  ///   [
+  ///     (Connection::Forward, "knows".to_string()),
+  ///     (Connection::Forward, "likes".to_string()),
+  ///     (Connection::Shared, "knows".to_string()),
+  ///   ]
+  /// );
+  /// assert!(triples[0] < triples[2]);
+  /// ```
+  pub fn cmp_by_connection(&self, other: &Triple) -> cmp::Ordering {
+    self
+      .connection
+      .cmp(&other.connection)
+      .then_with(|| Ord::cmp(&self.source, &other.source))
+      .then_with(|| {
+        predicate_text(&self.predicate).cmp(predicate_text(&other.predicate))
+      })
+      .then_with(|| Ord::cmp(&self.id, &other.id))
+  }
+}
+
+/// The text a `Predicate` displays as, without allocating.
+fn predicate_text(predicate: &Predicate) -> &str {
+  match predicate {
+    Predicate::Literal(s) => s,
+    Predicate::Uri(ns) => ns.full(),
+  }
 }
 
 impl PartialEq for Triple {
@@ -115,11 +238,9 @@ impl PartialEq for Triple {
   }
 }
 
-impl Default for Triple {
-  fn default() -> Self {
-    // TODO(victor): Add a default implementation like you did with `NamespaceStore`.
-    //  you might wanna create BlankNodes, Forward connection, and generic predicate.
-    Self::new()
+impl PartialOrd for Triple {
+  fn partial_cmp(&self, other: &Triple) -> Option<cmp::Ordering> {
+    Some(self.cmp_by_connection(other))
   }
 }
 
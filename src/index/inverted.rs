@@ -0,0 +1,173 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keyword search over `Entity` labels, descriptions & string payloads.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{
+  dtype::DType,
+  graph::{Entity, NodeId, NodeStore},
+};
+
+/// `InvertedIndex` maps each lowercased token to the `Entity`s whose label,
+/// description or string payload values contain it.
+///
+/// Text is tokenized by splitting on whitespace & punctuation, so
+/// `"Sci-Fi"` yields the tokens `sci` & `fi`.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::{
+///   graph::{Entity, NodeStore},
+///   index::InvertedIndex,
+///   json,
+/// };
+///
+/// let mut nodes = NodeStore::new();
+/// let mut avatar = Entity::schema("schema:Movie", "Avatar");
+/// avatar.payload_mut().insert("genre".to_string(), json!(["Sci-Fi", "Action"]));
+/// nodes.insert(avatar);
+/// nodes.insert(Entity::schema("schema:Movie", "Titanic").with_description("A romance."));
+///
+/// let mut index = InvertedIndex::build_from_store(&nodes);
+/// let hits = index.search(&nodes, "sci-fi avatar");
+/// assert_eq!(hits.len(), 1);
+/// assert_eq!(hits[0].label(), Some("Avatar"));
+///
+/// assert_eq!(index.search(&nodes, "ROMANCE")[0].label(), Some("Titanic"));
+/// assert!(index.search(&nodes, "avatar romance").is_empty());
+///
+/// // Re-index an entity after changing it.
+/// let id = nodes.get_by_label("Titanic").unwrap().id().clone();
+/// nodes.get_mut(&id).unwrap().payload_mut().insert("genre".to_string(), json!("Drama"));
+/// index.update(nodes.get(&id).unwrap());
+/// assert_eq!(index.search(&nodes, "drama").len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InvertedIndex {
+  /// Ids of the entities containing each token, in indexing order.
+  postings: HashMap<String, Vec<NodeId>>,
+  /// Tokens indexed for each entity, so that they can be re-indexed.
+  tokens: HashMap<NodeId, BTreeSet<String>>,
+}
+
+impl InvertedIndex {
+  /// Creates an empty `InvertedIndex`.
+  pub fn new() -> InvertedIndex {
+    InvertedIndex::default()
+  }
+
+  /// Indexes every `Entity` in `store`.
+  pub fn build_from_store(store: &NodeStore) -> InvertedIndex {
+    let mut index = InvertedIndex::new();
+    store.iter().for_each(|entity| index.update(entity));
+    index
+  }
+
+  /// Indexes `entity`, replacing whatever was indexed for its id before.
+  pub fn update(&mut self, entity: &Entity) {
+    self.remove(entity.id());
+
+    let mut tokens = BTreeSet::new();
+    for text in entity.label().into_iter().chain(entity.description()) {
+      tokens.extend(tokenize(text));
+    }
+    for value in entity.payload().values() {
+      collect_tokens(value, &mut tokens);
+    }
+
+    for token in &tokens {
+      self
+        .postings
+        .entry(token.clone())
+        .or_default()
+        .push(entity.id().clone());
+    }
+    self.tokens.insert(entity.id().clone(), tokens);
+  }
+
+  /// Removes the entity `id` from the index.
+  pub fn remove(&mut self, id: &NodeId) {
+    for token in self.tokens.remove(id).unwrap_or_default() {
+      if let Some(ids) = self.postings.get_mut(&token) {
+        ids.retain(|i| i != id);
+        if ids.is_empty() {
+          self.postings.remove(&token);
+        }
+      }
+    }
+  }
+
+  /// Returns the ids of the entities containing every token of `query`.
+  /// A query without tokens matches nothing.
+  pub fn search_ids(&self, query: &str) -> Vec<&NodeId> {
+    let postings = tokenize(query)
+      .map(|token| self.postings.get(&token).map_or(&[][..], Vec::as_slice))
+      .collect::<Vec<_>>();
+    let shortest = match postings.iter().min_by_key(|ids| ids.len()) {
+      Some(shortest) => shortest,
+      None => return vec![],
+    };
+
+    shortest
+      .iter()
+      .filter(|id| postings.iter().all(|ids| ids.contains(id)))
+      .collect()
+  }
+
+  /// Returns the entities of `store` containing every token of `query`.
+  pub fn search<'a>(
+    &self,
+    store: &'a NodeStore,
+    query: &str,
+  ) -> Vec<&'a Entity> {
+    self
+      .search_ids(query)
+      .into_iter()
+      .filter_map(|id| store.get(id))
+      .collect()
+  }
+
+  /// Returns the number of distinct tokens indexed.
+  pub fn len(&self) -> usize {
+    self.postings.len()
+  }
+
+  /// Returns true if nothing has been indexed.
+  pub fn is_empty(&self) -> bool {
+    self.postings.is_empty()
+  }
+}
+
+/// Splits `text` into lowercased tokens on whitespace & punctuation.
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+  text
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|token| !token.is_empty())
+    .map(str::to_lowercase)
+}
+
+/// Collects the tokens of every string within `value`.
+fn collect_tokens(value: &DType, tokens: &mut BTreeSet<String>) {
+  match value {
+    DType::String(s) => tokens.extend(tokenize(s)),
+    DType::Array(values) => {
+      values.iter().for_each(|v| collect_tokens(v, tokens))
+    }
+    DType::Object(map) => map.values().for_each(|v| collect_tokens(v, tokens)),
+    _ => {}
+  }
+}
@@ -0,0 +1,373 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `sage::iri` validates & normalizes IRIs before they enter the Knowledge
+//! Graph.
+//!
+//! [`parse_iri`] implements a pragmatic subset of [RFC 3987]: the IRI must
+//! have a scheme, contain no whitespace, control or otherwise illegal
+//! characters, and every `%` must start a valid percent-encoding. The parsed
+//! IRI is normalized as described in [RFC 3986 § 6.2.2] & [§ 6.2.3]:
+//!
+//! - the scheme & host are lowercased,
+//! - percent-encodings are uppercased (`%3a` → `%3A`),
+//! - default ports are removed (`http://example.com:80` → `http://example.com`),
+//! - dot segments are resolved (`/a/./b/../c` → `/a/c`),
+//! - an empty `http(s)` path becomes `/`.
+//!
+//! ```rust
+//! use sage::iri::parse_iri;
+//!
+//! let a = parse_iri("HTTPS://Schema.ORG:443/a/./b/../Person").unwrap();
+//! let b = parse_iri("https://schema.org/a/Person").unwrap();
+//! assert_eq!(a, b);
+//! assert_eq!(a.as_str(), "https://schema.org/a/Person");
+//!
+//! let err = parse_iri("https://schema.org/John Doe").unwrap_err();
+//! assert_eq!(err.column(), 24);
+//! assert!(parse_iri("schema.org/Person").is_err());
+//! ```
+//!
+//! [RFC 3987]: https://tools.ietf.org/html/rfc3987
+//! [RFC 3986 § 6.2.2]: https://tools.ietf.org/html/rfc3986#section-6.2.2
+//! [§ 6.2.3]: https://tools.ietf.org/html/rfc3986#section-6.2.3
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+  error::{Error, ErrorCode},
+  Result,
+};
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | `ParsedIri`.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// A validated & normalized IRI, split into its components.
+///
+/// This struct is constructed by [`parse_iri`] or `str::parse`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ParsedIri {
+  /// The normalized IRI.
+  iri: String,
+  scheme: String,
+  userinfo: Option<String>,
+  host: Option<String>,
+  port: Option<u16>,
+  path: String,
+  query: Option<String>,
+  fragment: Option<String>,
+}
+
+impl ParsedIri {
+  /// Returns the normalized IRI.
+  pub fn as_str(&self) -> &str {
+    &self.iri
+  }
+
+  /// Consumes the `ParsedIri`, returning the normalized IRI.
+  pub fn into_string(self) -> String {
+    self.iri
+  }
+
+  /// Returns the lowercased scheme, e.g. `"https"`.
+  pub fn scheme(&self) -> &str {
+    &self.scheme
+  }
+
+  /// Returns the user information of the authority, if any.
+  pub fn userinfo(&self) -> Option<&str> {
+    self.userinfo.as_deref()
+  }
+
+  /// Returns the lowercased host, if the IRI has an authority.
+  pub fn host(&self) -> Option<&str> {
+    self.host.as_deref()
+  }
+
+  /// Returns the port, unless absent or the scheme's default.
+  pub fn port(&self) -> Option<u16> {
+    self.port
+  }
+
+  /// Returns the path, with dot segments resolved.
+  pub fn path(&self) -> &str {
+    &self.path
+  }
+
+  /// Returns the query, without the leading `?`.
+  pub fn query(&self) -> Option<&str> {
+    self.query.as_deref()
+  }
+
+  /// Returns the fragment, without the leading `#`.
+  pub fn fragment(&self) -> Option<&str> {
+    self.fragment.as_deref()
+  }
+
+  /// Returns true if the scheme is `http` or `https`.
+  pub fn is_http(&self) -> bool {
+    self.scheme == "http" || self.scheme == "https"
+  }
+}
+
+impl fmt::Display for ParsedIri {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&self.iri)
+  }
+}
+
+impl FromStr for ParsedIri {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    parse_iri(s)
+  }
+}
+
+/*
+ * +----------------------------------------------------------------------+
+ * | +------------------------------------------------------------------+ |
+ * | | Parsing.
+ * | +------------------------------------------------------------------+ |
+ * +----------------------------------------------------------------------+
+*/
+
+/// Validates & normalizes `iri`.
+///
+/// An invalid IRI produces an error whose [`Error::column`] is the
+/// (one-based, in characters) position of the offending character.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::iri::parse_iri;
+///
+/// let iri = parse_iri("http://Example.com:8080/films?id=%2f1#top").unwrap();
+/// assert_eq!(iri.scheme(), "http");
+/// assert_eq!(iri.host(), Some("example.com"));
+/// assert_eq!(iri.port(), Some(8080));
+/// assert_eq!(iri.path(), "/films");
+/// assert_eq!(iri.query(), Some("id=%2F1"));
+/// assert_eq!(iri.fragment(), Some("top"));
+///
+/// assert_eq!(parse_iri("http://example.com").unwrap().path(), "/");
+/// assert_eq!(
+///   parse_iri("urn:isbn:0451450523").unwrap().as_str(),
+///   "urn:isbn:0451450523"
+/// );
+///
+/// assert!(parse_iri("https://").is_err());
+/// assert!(parse_iri("https://schema.org:http/").is_err());
+/// assert!(parse_iri("https://schema.org/%zz").is_err());
+/// assert!(parse_iri("https://schema.org/<Person>").is_err());
+/// assert!(parse_iri("1http://schema.org").is_err());
+/// ```
+pub fn parse_iri(iri: &str) -> Result<ParsedIri> {
+  check_characters(iri)?;
+
+  let colon = iri
+    .find([':', '/', '?', '#'])
+    .filter(|&i| iri[i..].starts_with(':'))
+    .ok_or_else(|| invalid(iri, 0, "missing scheme"))?;
+  let scheme = &iri[..colon];
+  let mut chars = scheme.chars();
+  let valid_scheme = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+    && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+  if !valid_scheme {
+    return Err(invalid(iri, 0, "malformed scheme"));
+  }
+  let scheme = scheme.to_ascii_lowercase();
+
+  let mut rest = &iri[colon + 1..];
+  let (mut userinfo, mut host, mut port) = (None, None, None);
+  if let Some(after) = rest.strip_prefix("//") {
+    let start = iri.len() - after.len();
+    let end = after.find(['/', '?', '#']).unwrap_or(after.len());
+    let authority = &after[..end];
+    rest = &after[end..];
+
+    let host_port = match authority.rfind('@') {
+      Some(at) => {
+        userinfo = Some(normalize_percent(&authority[..at]));
+        &authority[at + 1..]
+      }
+      None => authority,
+    };
+    let host_start = start + authority.len() - host_port.len();
+    // IPv6 literals (`[::1]`) contain colons, so only look for the port
+    // after the closing bracket.
+    let port_search = host_port.rfind(']').unwrap_or(0);
+    let (h, p) = match host_port[port_search..].rfind(':') {
+      Some(i) => host_port.split_at(port_search + i),
+      None => (host_port, ""),
+    };
+    if let Some(p) = p.strip_prefix(':').filter(|p| !p.is_empty()) {
+      let n = p
+        .parse::<u16>()
+        .map_err(|_| invalid(iri, host_start + h.len() + 1, "invalid port"))?;
+      if default_port(&scheme) != Some(n) {
+        port = Some(n);
+      }
+    }
+    host = Some(normalize_percent(h).to_lowercase());
+  }
+
+  let is_http = scheme == "http" || scheme == "https";
+  if is_http && host.as_deref().is_none_or(str::is_empty) {
+    return Err(invalid(iri, colon + 1, "missing host"));
+  }
+
+  let (rest, fragment) = match rest.split_once('#') {
+    Some((r, f)) => (r, Some(normalize_percent(f))),
+    None => (rest, None),
+  };
+  let (path, query) = match rest.split_once('?') {
+    Some((p, q)) => (p, Some(normalize_percent(q))),
+    None => (rest, None),
+  };
+  let mut path = normalize_percent(path);
+  if host.is_some() || path.starts_with('/') {
+    path = remove_dot_segments(&path);
+  }
+  if is_http && path.is_empty() {
+    path.push('/');
+  }
+
+  let mut normalized = format!("{}:", scheme);
+  if let Some(host) = &host {
+    normalized.push_str("//");
+    if let Some(userinfo) = &userinfo {
+      normalized.push_str(userinfo);
+      normalized.push('@');
+    }
+    normalized.push_str(host);
+    if let Some(port) = port {
+      normalized.push_str(&format!(":{}", port));
+    }
+  }
+  normalized.push_str(&path);
+  if let Some(query) = &query {
+    normalized.push('?');
+    normalized.push_str(query);
+  }
+  if let Some(fragment) = &fragment {
+    normalized.push('#');
+    normalized.push_str(fragment);
+  }
+
+  Ok(ParsedIri {
+    iri: normalized,
+    scheme,
+    userinfo,
+    host,
+    port,
+    path,
+    query,
+    fragment,
+  })
+}
+
+/// Builds an `InvalidIri` error pointing at byte offset `at` of `iri`.
+pub(crate) fn invalid(iri: &str, at: usize, reason: &str) -> Error {
+  let column = iri[..at].chars().count() + 1;
+  Error::syntax(ErrorCode::InvalidIri, 1, column)
+    .with_context(format!("{:?} ({})", iri, reason))
+}
+
+/// Rejects whitespace, control & other characters not allowed in IRIs, and
+/// malformed percent-encodings.
+fn check_characters(iri: &str) -> Result<()> {
+  let bytes = iri.as_bytes();
+  for (i, c) in iri.char_indices() {
+    if c.is_whitespace() || c.is_control() {
+      return Err(invalid(iri, i, "whitespace or control character"));
+    }
+    if matches!(c, '<' | '>' | '"' | '{' | '}' | '|' | '\\' | '^' | '`') {
+      return Err(invalid(iri, i, "illegal character"));
+    }
+    if c == '%'
+      && !(bytes.get(i + 1).is_some_and(u8::is_ascii_hexdigit)
+        && bytes.get(i + 2).is_some_and(u8::is_ascii_hexdigit))
+    {
+      return Err(invalid(iri, i, "malformed percent-encoding"));
+    }
+  }
+  Ok(())
+}
+
+/// Uppercases the hex digits of percent-encodings.
+fn normalize_percent(s: &str) -> String {
+  let mut out = String::with_capacity(s.len());
+  let mut rest = s;
+  while let Some(i) = rest.find('%') {
+    out.push_str(&rest[..i]);
+    out.push('%');
+    // `check_characters` guarantees two hex digits follow.
+    out.push_str(&rest[i + 1..i + 3].to_ascii_uppercase());
+    rest = &rest[i + 3..];
+  }
+  out.push_str(rest);
+  out
+}
+
+fn default_port(scheme: &str) -> Option<u16> {
+  match scheme {
+    "http" | "ws" => Some(80),
+    "https" | "wss" => Some(443),
+    "ftp" => Some(21),
+    _ => None,
+  }
+}
+
+/// Resolves `.` & `..` segments, as in [RFC 3986 § 5.2.4].
+///
+/// [RFC 3986 § 5.2.4]: https://tools.ietf.org/html/rfc3986#section-5.2.4
+fn remove_dot_segments(path: &str) -> String {
+  fn pop_segment(output: &mut String) {
+    let end = output.rfind('/').unwrap_or(0);
+    output.truncate(end);
+  }
+
+  let mut input = path;
+  let mut output = String::with_capacity(path.len());
+  while !input.is_empty() {
+    if let Some(rest) = input.strip_prefix("../") {
+      input = rest;
+    } else if let Some(rest) = input.strip_prefix("./") {
+      input = rest;
+    } else if input.starts_with("/./") {
+      input = &input[2..];
+    } else if input == "/." {
+      input = "/";
+    } else if input.starts_with("/../") {
+      input = &input[3..];
+      pop_segment(&mut output);
+    } else if input == "/.." {
+      input = "/";
+      pop_segment(&mut output);
+    } else if input == "." || input == ".." {
+      input = "";
+    } else {
+      let end = input[1..].find('/').map_or(input.len(), |i| i + 1);
+      output.push_str(&input[..end]);
+      input = &input[end..];
+    }
+  }
+  output
+}
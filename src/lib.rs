@@ -25,12 +25,18 @@
   clippy::needless_doctest_main
 )]
 
+// Lets `#[sage_vocab]` expand to `::sage::...` paths inside this crate too.
+extern crate self as sage;
+
 pub mod error;
 pub mod graph;
 #[macro_use]
 mod macros;
 mod datastore;
 pub mod dtype;
+#[cfg(feature = "index")]
+pub mod index;
+pub mod iri;
 mod processor;
 mod query;
 pub mod schema;
@@ -51,11 +57,13 @@ pub mod prelude {
   // Sage Error handler functionalities.
   pub use crate::error::*;
 
-  // Sage datastore.
-  pub use crate::datastore::json;
+  // Sage datastore (`sage::json`) & the `json!` macro.
+  pub use crate::json;
 
   // Sage types.
   pub use crate::dtype::*;
+  // `dtype::URI` & `vocab::URI` would otherwise be ambiguous.
+  pub use crate::dtype::URI;
 
   // Sage vocabularies.
   pub use crate::vocab::*;
@@ -69,7 +77,14 @@ pub mod prelude {
 
   // Export macros.
   pub use crate::macros::*;
+  pub use crate::triple;
+
+  // Sage `Result` aliases.
+  pub use crate::{Result, SageError, SageResult};
 }
 
 // Expose `sage::prelude` by default.
 pub use prelude::*;
+
+// `sage::json` is both the JSON datastore module & the `json!` macro.
+pub use datastore::json;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod json;
+mod triple;
 
 #[macro_export]
 #[doc(hidden)]
@@ -27,3 +28,26 @@ macro_rules! tri {
     tri!($e)
   };
 }
+
+/// Construct an `Error` from an `ErrorCode`, recording where in the source it
+/// was raised. An optional format string adds context to the message.
+///
+/// ```ignore
+/// return Err(sage_err!(ErrorCode::RegexParser, "invalid node id {:?}", s));
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! sage_err {
+  ($code:expr) => {
+    $crate::error::Error::syntax($code, 0, 0).with_location(
+      $crate::error::SourceLocation {
+        file: ::std::option::Option::Some(::std::string::String::from(file!())),
+        line: line!(),
+        column: column!(),
+      },
+    )
+  };
+  ($code:expr, $($arg:tt)+) => {
+    $crate::sage_err!($code).with_context(format!($($arg)+))
+  };
+}
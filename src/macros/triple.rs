@@ -0,0 +1,89 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `triple!` macro builds a [`Triple`] from arrow notation.
+//!
+//! [`Triple`]: crate::graph::Triple
+
+/// Construct a [`Triple`], adding its source & destination entities to a
+/// [`NodeStore`].
+///
+/// The arrow picks the `Connection`:
+///
+/// - `--"pred"-->` creates a `Connection::Forward` triple.
+/// - `<--"pred"-->` creates a `Connection::Shared` triple.
+/// - `--"pred"-[*]->` creates a `Connection::Multiple` triple.
+///
+/// Endpoints become nodes as [`Node::from_iri`] does: `http(s)` IRIs become
+/// `Node::Http`, plain strings become `Node::Literal`. An endpoint reuses the
+/// entity already holding the same node in the store, so an IRI used in
+/// several triples refers to a single entity; otherwise a new entity is
+/// added.
+///
+/// ```rust
+/// use sage::graph::{Connection, NodeStore};
+/// use sage::triple;
+///
+/// let mut store = NodeStore::new();
+/// let born_in = triple!(store;
+///   "https://example.org/john" --"born_in"--> "https://example.org/london"
+/// );
+/// let friends = triple!(store;
+///   "https://example.org/jane" <--"friend_of"--> "https://example.org/john"
+/// );
+/// let directed = triple!(store; "James Cameron" --"directed"-[*]-> "Avatar");
+///
+/// assert_eq!(born_in.connection(), &Connection::Forward);
+/// assert_eq!(friends.connection(), &Connection::Shared);
+/// assert_eq!(directed.connection(), &Connection::Multiple);
+///
+/// assert_eq!(born_in.predicate().to_string(), "born_in");
+/// assert!(store.get(born_in.source()).unwrap().node().is_http());
+/// assert!(store.get(directed.destination()).unwrap().node().is_literal());
+///
+/// // "https://example.org/john" is the same entity in both triples.
+/// assert_eq!(friends.destination(), born_in.source());
+/// assert_eq!(store.len(), 5);
+/// ```
+///
+/// [`Triple`]: crate::graph::Triple
+/// [`NodeStore`]: crate::graph::NodeStore
+/// [`Node::from_iri`]: crate::graph::Node::from_iri
+#[macro_export]
+macro_rules! triple {
+  ($store:expr; $src:tt --$pred:tt--> $dst:tt) => {
+    $crate::triple!(@build $store, $src, $pred, $dst, Forward)
+  };
+  ($store:expr; $src:tt <--$pred:tt--> $dst:tt) => {
+    $crate::triple!(@build $store, $src, $pred, $dst, Shared)
+  };
+  ($store:expr; $src:tt --$pred:tt-[*]-> $dst:tt) => {
+    $crate::triple!(@build $store, $src, $pred, $dst, Multiple)
+  };
+
+  (@build $store:expr, $src:tt, $pred:tt, $dst:tt, $connection:ident) => {{
+    let store: &mut $crate::graph::NodeStore = &mut $store;
+    let source = store.get_or_add($crate::graph::Node::from_iri(&$src));
+    let destination =
+      store.get_or_add($crate::graph::Node::from_iri(&$dst));
+    $crate::graph::Triple::new(
+      &source,
+      $crate::graph::Predicate::Literal(::std::string::ToString::to_string(
+        &$pred,
+      )),
+      &destination,
+      $crate::graph::Connection::$connection,
+    )
+  }};
+}
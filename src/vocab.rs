@@ -18,17 +18,46 @@
 //! [Resource Description Framework (RDF)]: https://en.wikipedia.org/wiki/Resource_Description_Framework
 //!
 
+mod hierarchy;
 mod namespace;
 mod rdf;
 mod rdfs;
 mod schema;
+mod schemaorg;
 mod vocabulary;
 
 // Ambiguous export.
 pub use crate::vocab::rdf::RdfVocab;
 
 // Unambiguous export.
+pub use hierarchy::TypeHierarchy;
 pub use namespace::{Namespace, NamespaceStore, Namespaces, URI};
 pub use rdfs::RdfsVocab;
 pub use schema::SchemaVocab;
+pub use schemaorg::{SchemaClass, SchemaOrg, SchemaProperty};
 pub use vocabulary::Vocabulary;
+
+/// Implements [`Vocabulary`] for a (zero-sized) struct.
+///
+/// `prefix` must end with `:` and `full` with `/` or `#`. An optional
+/// `terms(...)` list adds an associated constant holding the full IRI of each
+/// term.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::vocab::{sage_vocab, Vocabulary};
+///
+/// #[sage_vocab(
+///   prefix = "ex:",
+///   full = "https://example.com/",
+///   terms(PERSON = "Person", KNOWS = "knows")
+/// )]
+/// pub struct ExVocab;
+///
+/// assert_eq!(ExVocab::prefix(), "ex:");
+/// assert_eq!(ExVocab::full(), "https://example.com/");
+/// assert_eq!(ExVocab::PERSON, "https://example.com/Person");
+/// assert_eq!(ExVocab::KNOWS, "https://example.com/knows");
+/// ```
+pub use sage_macros::sage_vocab;
@@ -0,0 +1,167 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `rdfs:subClassOf` type hierarchies, e.g. schema.org's `Movie` being a
+//! `CreativeWork` which is in turn a `Thing`.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::dtype::{DType, IRI};
+
+/// `TypeHierarchy` records `rdfs:subClassOf` relations between class `IRI`s
+/// and answers subtype queries over them.
+///
+/// A class may have several parents. Cycles, which do occur in dirty
+/// vocabularies, are tolerated: every query visits each class at most once.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::vocab::TypeHierarchy;
+///
+/// let mut types = TypeHierarchy::new();
+/// types.add_subclass("schema:CreativeWork", "schema:Thing");
+/// types.add_subclass("schema:Movie", "schema:CreativeWork");
+/// types.add_subclass("schema:Person", "schema:Thing");
+///
+/// // A deliberate cycle: A -> B -> C -> A.
+/// types.add_subclass("ex:A", "ex:B");
+/// types.add_subclass("ex:B", "ex:C");
+/// types.add_subclass("ex:C", "ex:A");
+///
+/// assert!(types.is_subclass_of("schema:Movie", "schema:Thing"));
+/// assert!(!types.is_subclass_of("schema:Movie", "schema:Person"));
+/// assert_eq!(types.ancestors("schema:Movie"), vec!["schema:CreativeWork", "schema:Thing"]);
+///
+/// assert!(types.is_subclass_of("ex:A", "ex:C"));
+/// assert_eq!(types.ancestors("ex:A"), vec!["ex:B", "ex:C"]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TypeHierarchy {
+  /// Direct parents of each class.
+  parents: HashMap<IRI, Vec<IRI>>,
+}
+
+impl TypeHierarchy {
+  /// Creates an empty `TypeHierarchy`.
+  pub fn new() -> TypeHierarchy {
+    TypeHierarchy {
+      parents: HashMap::new(),
+    }
+  }
+
+  /// Loads a hierarchy from a schema.org style class tree, where every class
+  /// object has an `"@id"`, an optional `"rdfs:subClassOf"` (one `IRI` or a
+  /// list of them) and its subclasses nested under `"children"`.
+  ///
+  /// This is the format of `resources/schema/tree.jsonld`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::{json, vocab::TypeHierarchy};
+  ///
+  /// let tree = json!({
+  ///   "@id": "schema:Thing",
+  ///   "children": [{
+  ///     "@id": "schema:CreativeWork",
+  ///     "rdfs:subClassOf": "schema:Thing",
+  ///     "children": [{
+  ///       "@id": "schema:Movie",
+  ///       "rdfs:subClassOf": "schema:CreativeWork"
+  ///     }]
+  ///   }]
+  /// });
+  ///
+  /// let types = TypeHierarchy::from_schema_tree(&tree);
+  /// assert!(types.is_subclass_of("schema:Movie", "schema:Thing"));
+  /// ```
+  pub fn from_schema_tree(tree: &DType) -> TypeHierarchy {
+    let mut hierarchy = TypeHierarchy::new();
+    // (class, parent it is nested under)
+    let mut stack: Vec<(&DType, Option<&str>)> = vec![(tree, None)];
+
+    while let Some((class, nested_under)) = stack.pop() {
+      let id = match class["@id"].as_str() {
+        Some(id) => id,
+        None => continue,
+      };
+      if let Some(parent) = nested_under {
+        hierarchy.add_subclass(id, parent);
+      }
+      match &class["rdfs:subClassOf"] {
+        DType::String(parent) => hierarchy.add_subclass(id, parent),
+        DType::Array(parents) => parents
+          .iter()
+          .filter_map(DType::as_str)
+          .for_each(|parent| hierarchy.add_subclass(id, parent)),
+        _ => {}
+      }
+      if let Some(children) = class["children"].as_array() {
+        stack.extend(children.iter().map(|child| (child, Some(id))));
+      }
+    }
+    hierarchy
+  }
+
+  /// Records that `child` is a direct subclass of `parent`.
+  pub fn add_subclass(&mut self, child: &str, parent: &str) {
+    let parents = self.parents.entry(child.to_string()).or_default();
+    if !parents.iter().any(|p| p == parent) {
+      parents.push(parent.to_string());
+    }
+  }
+
+  /// Returns the direct parents of `class`.
+  pub fn parents(&self, class: &str) -> &[IRI] {
+    self.parents.get(class).map_or(&[], Vec::as_slice)
+  }
+
+  /// Returns every ancestor of `class`, nearest first. `class` itself is
+  /// never included, even when it is part of a cycle.
+  pub fn ancestors(&self, class: &str) -> Vec<IRI> {
+    let mut seen: HashSet<&str> = HashSet::new();
+    seen.insert(class);
+
+    let mut ancestors = Vec::new();
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    queue.push_back(class);
+
+    while let Some(current) = queue.pop_front() {
+      for parent in self.parents(current) {
+        if seen.insert(parent) {
+          ancestors.push(parent.clone());
+          queue.push_back(parent);
+        }
+      }
+    }
+    ancestors
+  }
+
+  /// Returns true if `child` is `parent` or one of its (transitive)
+  /// subclasses, as `rdfs:subClassOf` is reflexive.
+  pub fn is_subclass_of(&self, child: &str, parent: &str) -> bool {
+    child == parent || self.ancestors(child).iter().any(|a| a == parent)
+  }
+
+  /// Returns the number of classes with at least one recorded parent.
+  pub fn len(&self) -> usize {
+    self.parents.len()
+  }
+
+  /// Returns true if no subclass relation has been recorded.
+  pub fn is_empty(&self) -> bool {
+    self.parents.is_empty()
+  }
+}
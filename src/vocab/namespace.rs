@@ -12,8 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::dtype::IRI;
+use crate::{dtype::IRI, iri::parse_iri, Result};
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// `URI` expands and contracts a URL given it's context and the property.
@@ -62,14 +63,17 @@ impl URI {
 }
 
 /// Namespace is a RDF namespace (vocabulary).
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Namespace {
   prefix: IRI,
   full: IRI,
 }
 
 impl Namespace {
-  /// Creates a new namespace using `IRI` values.
+  /// Creates a new namespace using `IRI` values, as-is.
+  ///
+  /// Deprecated since `full` isn't validated, so typos such as
+  /// `"htps://schema.org/"` slip through; use [`Namespace::try_new`].
   ///
   /// # Example
   ///
@@ -85,14 +89,38 @@ impl Namespace {
   /// assert_eq!(ns.full(), &full);
   /// ```
   ///
+  #[deprecated(note = "use try_new")]
   pub fn new(prefix: &str, full: &str) -> Namespace {
-    Namespace {
+    Namespace::unchecked(prefix, full)
+  }
+
+  /// Like [`Namespace::new`], but validates & normalizes the `full` IRI with
+  /// [`parse_iri`], so typos surface here rather than at export time.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::vocab::Namespace;
+  ///
+  /// let ns = Namespace::try_new("schema:Person", "HTTPS://Schema.org/Person")
+  ///   .unwrap();
+  /// assert_eq!(ns.full(), "https://schema.org/Person");
+  ///
+  /// assert!(Namespace::try_new("schema:Person", "schema.org/Person").is_err());
+  /// ```
+  ///
+  /// [`parse_iri`]: crate::iri::parse_iri
+  pub fn try_new(prefix: &str, full: &str) -> Result<Namespace> {
+    Ok(Namespace {
       prefix: prefix.to_string(),
-      full: full.to_string(),
-    }
+      full: parse_iri(full)?.into_string(),
+    })
   }
 
-  /// Creates a new namespace from a string slice.
+  /// Creates a new namespace from a string slice, as-is.
+  ///
+  /// Deprecated for the same reason as [`Namespace::new`]; use
+  /// [`Namespace::try_new`].
   ///
   /// # Example
   ///
@@ -106,7 +134,14 @@ impl Namespace {
   /// assert_eq!(ns.full(), "http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
   /// ```
   ///
+  #[deprecated(note = "use try_new")]
   pub fn from(prefix: &str, full: &str) -> Namespace {
+    Namespace::unchecked(prefix, full)
+  }
+
+  /// Creates a namespace without validating `full`, for the built-in
+  /// vocabularies & IRIs that are kept verbatim on purpose.
+  pub(crate) fn unchecked(prefix: &str, full: &str) -> Namespace {
     Namespace {
       prefix: prefix.to_string(),
       full: full.to_string(),
@@ -121,7 +156,7 @@ impl Namespace {
   /// use sage::{ dtype::IRI, vocab::Namespace };
   ///
   /// // Creates a new namespace using a sing literal.
-  /// let ns = Namespace::from("rdf:type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
+  /// let ns = Namespace::try_new("rdf:type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type").unwrap();
   ///
   /// assert_eq!(ns.prefix(), "rdf:type");
   /// ```
@@ -137,7 +172,7 @@ impl Namespace {
   /// use sage::{ dtype::IRI, vocab::Namespace };
   ///
   /// // Creates a new namespace using a sing literal.
-  /// let ns = Namespace::from("rdf:type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
+  /// let ns = Namespace::try_new("rdf:type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type").unwrap();
   ///
   ///  assert_eq!(ns.full(), "http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
   /// ```
@@ -160,12 +195,12 @@ impl Default for Namespace {
   /// assert_eq!(ns.full(), "https://schema.org/Thing");
   /// ```
   fn default() -> Self {
-    Namespace::new("schema:Thing", "https://schema.org/Thing")
+    Namespace::unchecked("schema:Thing", "https://schema.org/Thing")
   }
 }
 
 /// `NamespaceStore` is a set of registered NamespaceStore.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct NamespaceStore {
   /// List of registered namespace prefix & full `IRI` values.
   ///
@@ -205,11 +240,11 @@ impl NamespaceStore {
   /// let mut ns = NamespaceStore::new();
   ///
   /// // Add a new namespace created from `Namespace::from` API.
-  /// ns.add(&Namespace::from("rdf:type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type"));
+  /// ns.add(&Namespace::try_new("rdf:type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type").unwrap());
   /// assert_eq!(ns.len(), 1);
   ///
   ///// Let's register another namespace.
-  /// ns.add(&Namespace::from("schema:Thing", "https://schema.org/Thing"));
+  /// ns.add(&Namespace::try_new("schema:Thing", "https://schema.org/Thing").unwrap());
   /// assert_eq!(ns.len(), 2);
   /// ```
   /// You could also use `NamespaceStore` alias -> `Namespaces`, in case you say it's too long.
@@ -224,11 +259,11 @@ impl NamespaceStore {
   /// let mut ns = Namespaces::new();
   ///
   /// // Add a new namespace created from `Namespace::from` API.
-  /// ns.add(&Namespace::from("rdf:type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type"));
+  /// ns.add(&Namespace::try_new("rdf:type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type").unwrap());
   /// assert_eq!(ns.len(), 1);
   ///
   ///// Let's register another namespace.
-  /// ns.add(&Namespace::from("schema:Thing", "https://schema.org/Thing"));
+  /// ns.add(&Namespace::try_new("schema:Thing", "https://schema.org/Thing").unwrap());
   /// assert_eq!(ns.len(), 2);
   ///
   pub fn add(&mut self, ns: &Namespace) {
@@ -281,11 +316,11 @@ impl NamespaceStore {
   ///
   /// // You can use any collection that deref-s into `&[Namespace]`
   /// let ns_list: Vec<Namespace> = vec![
-  ///   Namespace::from(
+  ///   Namespace::try_new(
   ///     "rdf:type",
   ///     "http://www.w3.org/1999/02/22-rdf-syntax-ns#type",
-  ///   ),
-  ///   Namespace::from("schema:Thing", "https://schema.org/Thing"),
+  ///   ).unwrap(),
+  ///   Namespace::try_new("schema:Thing", "https://schema.org/Thing").unwrap(),
   /// ];
   ///
   /// // Add a collection of namespace objects.
@@ -374,6 +409,86 @@ impl NamespaceStore {
     }
   }
 
+  /// `NamespaceStore::resolve_iri` expands an `IRI` given in either compact or full form.
+  ///
+  /// - `http://` & `https://` IRIs are already full and are returned as-is.
+  /// - A registered term (e.g. `"rdf:type"`) is replaced by its full `IRI`.
+  /// - A `prefix:localname` whose `"prefix:"` is registered is expanded.
+  /// - Anything else is returned as-is.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::vocab::NamespaceStore;
+  ///
+  /// let mut ns = NamespaceStore::new();
+  /// ns.add_prefix("schema:", "https://schema.org/");
+  /// ns.add_prefix("rdf:type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
+  ///
+  /// assert_eq!(ns.resolve_iri("schema:Person"), "https://schema.org/Person");
+  /// assert_eq!(
+  ///   ns.resolve_iri("rdf:type"),
+  ///   "http://www.w3.org/1999/02/22-rdf-syntax-ns#type"
+  /// );
+  /// assert_eq!(ns.resolve_iri("https://schema.org/Movie"), "https://schema.org/Movie");
+  /// assert_eq!(ns.resolve_iri("foaf:name"), "foaf:name");
+  /// ```
+  ///
+  pub fn resolve_iri(&self, iri: &str) -> IRI {
+    if iri.starts_with("http://") || iri.starts_with("https://") {
+      return iri.to_string();
+    }
+    if let Some(full) = self.prefixes.get(iri) {
+      return full.to_string();
+    }
+    if let Some((prefix, local)) = iri.split_once(':') {
+      if let Some(full) = self.prefixes.get(&format!("{}:", prefix)) {
+        return format!("{}{}", full, local);
+      }
+    }
+    iri.to_string()
+  }
+
+  /// `NamespaceStore::compact_iri` replaces the longest registered base `IRI` in `full`
+  /// with its prefix.
+  ///
+  /// Registered terms (e.g. `"rdf:type"`) must match `full` exactly, while
+  /// namespace prefixes ending with a colon (e.g. `"schema:"`) match any `IRI`
+  /// starting with their base `IRI`. Unknown IRIs are returned as-is.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::vocab::NamespaceStore;
+  ///
+  /// let mut ns = NamespaceStore::new();
+  /// ns.add_prefix("schema:", "https://schema.org/");
+  /// ns.add_prefix("ext:", "https://schema.org/extensions/");
+  ///
+  /// assert_eq!(ns.compact_iri("https://schema.org/Person"), "schema:Person");
+  /// assert_eq!(ns.compact_iri("https://schema.org/extensions/Robot"), "ext:Robot");
+  /// assert_eq!(ns.compact_iri("https://example.com/x"), "https://example.com/x");
+  /// ```
+  ///
+  pub fn compact_iri(&self, full: &str) -> IRI {
+    let mut best: Option<(&IRI, &IRI)> = None;
+    for (prefix, base) in self.prefixes.iter() {
+      if base == full {
+        return prefix.to_string();
+      }
+      if prefix.ends_with(':')
+        && full.starts_with(base.as_str())
+        && best.is_none_or(|(_, b)| base.len() > b.len())
+      {
+        best = Some((prefix, base));
+      }
+    }
+    match best {
+      Some((prefix, base)) => format!("{}{}", prefix, &full[base.len()..]),
+      None => full.to_string(),
+    }
+  }
+
   /// `NamespaceStore::len` returns the number of registered namespace.
   ///
   /// # Example
@@ -432,11 +547,11 @@ impl NamespaceStore {
   /// let mut ns = NamespaceStore::new();
   ///
   /// // Create a few namespace to be registered.
-  /// let rdf = Namespace::from(
+  /// let rdf = Namespace::try_new(
   ///   "rdf:type",
   ///   "http://www.w3.org/1999/02/22-rdf-syntax-ns#type",
-  /// );
-  /// let schema = Namespace::from("schema:Thing", "https://schema.org/Thing");
+  /// ).unwrap();
+  /// let schema = Namespace::try_new("schema:Thing", "https://schema.org/Thing").unwrap();
   ///
   /// // Add created namespace.
   /// ns.add(&rdf);
@@ -478,9 +593,9 @@ impl Default for NamespaceStore {
 
     // Add the default vocabularies.
     let ns_list: Vec<Namespace> = vec![
-      Namespace::new(&RdfVocab::prefix(), &RdfVocab::full()),
-      Namespace::new(&RdfsVocab::prefix(), &RdfsVocab::full()),
-      Namespace::new(&SchemaVocab::prefix(), &SchemaVocab::full()),
+      Namespace::unchecked(&RdfVocab::prefix(), &RdfVocab::full()),
+      Namespace::unchecked(&RdfsVocab::prefix(), &RdfsVocab::full()),
+      Namespace::unchecked(&SchemaVocab::prefix(), &SchemaVocab::full()),
     ];
 
     // Add a collection of namespace objects.
@@ -14,7 +14,7 @@
 
 //! Module `rdf` contains constants of the RDF Concepts Vocabulary (RDF)
 
-use crate::{dtype::IRI, vocab::Vocabulary};
+use crate::vocab::sage_vocab;
 
 /// `RdfVocab` contains constants of the Resource Description Framework (RDF) vocabulary.
 ///
@@ -30,21 +30,12 @@ use crate::{dtype::IRI, vocab::Vocabulary};
 /// assert_eq!(RdfVocab::prefix(), IRI::from("rdf:"));
 /// assert_eq!(RdfVocab::full(), IRI::from("http://www.w3.org/1999/02/22-rdf-syntax-ns#"));
 /// ```
+#[sage_vocab(
+  prefix = "rdf:",
+  full = "http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+)]
 pub struct RdfVocab;
 
-impl Vocabulary for RdfVocab {
-  type Prefix = IRI;
-  type Full = IRI;
-
-  fn prefix() -> Self::Prefix {
-    IRI::from("rdf:")
-  }
-
-  fn full() -> Self::Full {
-    IRI::from("http://www.w3.org/1999/02/22-rdf-syntax-ns#")
-  }
-}
-
 /*
  * +----------------------------------------------------------------------+
  * | +------------------------------------------------------------------+ |
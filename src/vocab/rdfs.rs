@@ -14,7 +14,7 @@
 
 //! Module `rdfs` contains constants of the RDF Schema vocabulary (RDFS)
 
-use crate::{dtype::IRI, vocab::Vocabulary};
+use crate::vocab::sage_vocab;
 
 /// `RdfsVocab` contains constants of the RDFs vocabulary (RDFS).
 ///
@@ -30,21 +30,9 @@ use crate::{dtype::IRI, vocab::Vocabulary};
 /// assert_eq!(RdfsVocab::prefix(), IRI::from("rdfs:"));
 /// assert_eq!(RdfsVocab::full(), IRI::from("http://www.w3.org/2000/01/rdf-schema#"));
 /// ```
+#[sage_vocab(prefix = "rdfs:", full = "http://www.w3.org/2000/01/rdf-schema#")]
 pub struct RdfsVocab;
 
-impl Vocabulary for RdfsVocab {
-  type Prefix = IRI;
-  type Full = IRI;
-
-  fn prefix() -> Self::Prefix {
-    IRI::from("rdfs:")
-  }
-
-  fn full() -> Self::Full {
-    IRI::from("http://www.w3.org/2000/01/rdf-schema#")
-  }
-}
-
 /*
  * +----------------------------------------------------------------------+
  * | +------------------------------------------------------------------+ |
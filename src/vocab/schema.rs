@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{dtype::IRI, vocab::Vocabulary};
+use crate::{
+  dtype::IRI,
+  vocab::{sage_vocab, Vocabulary},
+};
 
 /// `SchemaVocab` contains constants of the <https://schema.org> vocabulary.
 ///
@@ -28,17 +31,23 @@ use crate::{dtype::IRI, vocab::Vocabulary};
 /// assert_eq!(SchemaVocab::prefix(), IRI::from("schema:"));
 /// assert_eq!(SchemaVocab::full(), IRI::from("https://schema.org/"));
 /// ```
+#[sage_vocab(prefix = "schema:", full = "https://schema.org/")]
 pub struct SchemaVocab;
 
-impl Vocabulary for SchemaVocab {
-  type Prefix = IRI;
-  type Full = IRI;
-
-  fn prefix() -> Self::Prefix {
-    IRI::from("schema:")
-  }
-
-  fn full() -> Self::Full {
-    IRI::from("https://schema.org/")
+impl SchemaVocab {
+  /// Returns the prefixed (short) `IRI` of a term in the schema.org
+  /// vocabulary.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::vocab::SchemaVocab;
+  ///
+  /// assert_eq!(SchemaVocab::term("Person"), "schema:Person");
+  /// ```
+  pub fn term(name: &str) -> IRI {
+    let mut iri = Self::prefix();
+    iri.push_str(name);
+    iri
   }
 }
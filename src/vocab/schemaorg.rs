@@ -0,0 +1,253 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Machine-readable schema.org definitions: classes, their `rdfs:subClassOf`
+//! edges, and properties with their domain, range & inverse.
+//!
+//! With the `schemaorg` feature, [`SchemaOrg::get`] returns a trimmed
+//! snapshot of schema.org core bundled with sage (`src/vocab/data/`). To use
+//! the full vocabulary, or a custom one, download a schema.org release in
+//! JSON-LD (e.g. `schemaorg-current-https.jsonld` from
+//! <https://schema.org/docs/developers.html>) and load it with
+//! [`SchemaOrg::parse`]; this works with or without the feature.
+
+use std::collections::HashMap;
+#[cfg(feature = "schemaorg")]
+use std::sync::OnceLock;
+
+use crate::{
+  datastore::json,
+  dtype::{DType, IRI},
+  vocab::TypeHierarchy,
+  Result,
+};
+
+/// A schema.org class, e.g. `schema:Movie`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaClass {
+  /// Compact `IRI` of the class, e.g. `"schema:Movie"`.
+  pub id: IRI,
+  /// Human-readable name, e.g. `"Movie"`.
+  pub label: String,
+  /// Direct parents of the class.
+  pub sub_class_of: Vec<IRI>,
+}
+
+/// A schema.org property, e.g. `schema:director`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaProperty {
+  /// Compact `IRI` of the property, e.g. `"schema:director"`.
+  pub id: IRI,
+  /// Human-readable name, e.g. `"director"`.
+  pub label: String,
+  /// Classes the property may be used on (`schema:domainIncludes`).
+  pub domain: Vec<IRI>,
+  /// Classes the value may have (`schema:rangeIncludes`).
+  pub range: Vec<IRI>,
+  /// The inverse property, if any (`schema:inverseOf`).
+  pub inverse_of: Option<IRI>,
+  /// True for `owl:SymmetricProperty`s such as `schema:spouse`.
+  pub symmetric: bool,
+}
+
+/// schema.org classes & properties, indexed by their compact `IRI`.
+///
+/// Lookups accept compact (`"schema:Person"`) as well as full
+/// (`"https://schema.org/Person"`, `"http://schema.org/Person"`) `IRI`s.
+///
+/// # Example
+///
+/// ```rust
+/// use sage::{json, vocab::SchemaOrg};
+///
+/// let schema = SchemaOrg::from_jsonld(&json!({
+///   "@graph": [
+///     { "@id": "schema:Thing", "@type": "rdfs:Class" },
+///     {
+///       "@id": "schema:Person",
+///       "@type": "rdfs:Class",
+///       "rdfs:subClassOf": { "@id": "schema:Thing" }
+///     },
+///     {
+///       "@id": "schema:spouse",
+///       "@type": ["rdf:Property", "owl:SymmetricProperty"],
+///       "schema:domainIncludes": { "@id": "schema:Person" },
+///       "schema:rangeIncludes": { "@id": "schema:Person" }
+///     }
+///   ]
+/// }));
+///
+/// assert!(schema.is_subclass_of("https://schema.org/Person", "schema:Thing"));
+/// assert_eq!(schema.property("schema:spouse").unwrap().range, ["schema:Person"]);
+/// assert_eq!(schema.inverse_of("schema:spouse"), Some("schema:spouse"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchemaOrg {
+  classes: HashMap<IRI, SchemaClass>,
+  properties: HashMap<IRI, SchemaProperty>,
+  hierarchy: TypeHierarchy,
+}
+
+impl SchemaOrg {
+  /// Returns the schema.org core snapshot bundled with sage, parsed on first
+  /// use.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use sage::vocab::SchemaOrg;
+  ///
+  /// let schema = SchemaOrg::get();
+  /// assert!(schema.is_subclass_of("schema:Movie", "schema:CreativeWork"));
+  /// assert_eq!(schema.inverse_of("schema:alumniOf"), Some("schema:alumni"));
+  /// ```
+  #[cfg(feature = "schemaorg")]
+  pub fn get() -> &'static SchemaOrg {
+    static SCHEMA_ORG: OnceLock<SchemaOrg> = OnceLock::new();
+    SCHEMA_ORG.get_or_init(|| {
+      SchemaOrg::parse(include_str!("data/schemaorg-core.jsonld"))
+        .expect("bundled schema.org data is valid JSON")
+    })
+  }
+
+  /// Parses a schema.org JSON-LD dump, such as an official schema.org
+  /// release.
+  pub fn parse(jsonld: &str) -> Result<SchemaOrg> {
+    Ok(SchemaOrg::from_jsonld(&json::from_str(jsonld)?))
+  }
+
+  /// Builds a `SchemaOrg` from a JSON-LD document whose `"@graph"` (or the
+  /// document itself, if it's an array) lists `rdfs:Class` &
+  /// `rdf:Property` nodes. Other nodes are ignored.
+  pub fn from_jsonld(doc: &DType) -> SchemaOrg {
+    let nodes = match doc {
+      DType::Array(nodes) => nodes.as_slice(),
+      _ => doc["@graph"].as_array().map_or(&[][..], Vec::as_slice),
+    };
+
+    let mut schema = SchemaOrg::default();
+    for node in nodes {
+      let id = match node["@id"].as_str() {
+        Some(id) => compact(id),
+        None => continue,
+      };
+      let types = ids(&node["@type"]);
+      let label = match &node["rdfs:label"] {
+        DType::String(label) => label.clone(),
+        label => label["@value"]
+          .as_str()
+          .map_or_else(|| local_name(&id).to_string(), str::to_string),
+      };
+
+      if types.iter().any(|t| t == "rdfs:Class") {
+        let sub_class_of = ids(&node["rdfs:subClassOf"]);
+        for parent in &sub_class_of {
+          schema.hierarchy.add_subclass(&id, parent);
+        }
+        schema.classes.insert(
+          id.clone(),
+          SchemaClass {
+            id,
+            label,
+            sub_class_of,
+          },
+        );
+      } else if types.iter().any(|t| t == "rdf:Property") {
+        schema.properties.insert(
+          id.clone(),
+          SchemaProperty {
+            id,
+            label,
+            domain: ids(&node["schema:domainIncludes"]),
+            range: ids(&node["schema:rangeIncludes"]),
+            inverse_of: ids(&node["schema:inverseOf"]).into_iter().next(),
+            symmetric: types.iter().any(|t| t == "owl:SymmetricProperty"),
+          },
+        );
+      }
+    }
+    schema
+  }
+
+  /// Returns the class `iri` refers to.
+  pub fn class(&self, iri: &str) -> Option<&SchemaClass> {
+    self.classes.get(&compact(iri))
+  }
+
+  /// Returns the property `iri` refers to.
+  pub fn property(&self, iri: &str) -> Option<&SchemaProperty> {
+    self.properties.get(&compact(iri))
+  }
+
+  /// Returns an iterator over every class, in no particular order.
+  pub fn classes(&self) -> impl Iterator<Item = &SchemaClass> {
+    self.classes.values()
+  }
+
+  /// Returns an iterator over every property, in no particular order.
+  pub fn properties(&self) -> impl Iterator<Item = &SchemaProperty> {
+    self.properties.values()
+  }
+
+  /// Returns the class hierarchy.
+  pub fn hierarchy(&self) -> &TypeHierarchy {
+    &self.hierarchy
+  }
+
+  /// Returns true if `child` is `parent` or one of its (transitive)
+  /// subclasses.
+  pub fn is_subclass_of(&self, child: &str, parent: &str) -> bool {
+    self
+      .hierarchy
+      .is_subclass_of(&compact(child), &compact(parent))
+  }
+
+  /// Returns the inverse of `property`: its `schema:inverseOf`, or the
+  /// property itself when it is symmetric.
+  pub fn inverse_of(&self, property: &str) -> Option<&str> {
+    let property = self.property(property)?;
+    match &property.inverse_of {
+      Some(inverse) => Some(inverse),
+      None if property.symmetric => Some(&property.id),
+      None => None,
+    }
+  }
+}
+
+/// Shortens full schema.org `IRI`s to the `schema:` prefix.
+fn compact(iri: &str) -> IRI {
+  ["https://schema.org/", "http://schema.org/"]
+    .iter()
+    .find_map(|full| iri.strip_prefix(full))
+    .map_or_else(|| iri.to_string(), |local| format!("schema:{}", local))
+}
+
+/// Returns the part of `iri` after its prefix, e.g. `Person` for
+/// `schema:Person`.
+fn local_name(iri: &str) -> &str {
+  iri.rsplit([':', '/', '#']).next().unwrap_or(iri)
+}
+
+/// Collects the compact `IRI`s of a JSON-LD reference (`"schema:Thing"` or
+/// `{ "@id": "schema:Thing" }`) or a list of them.
+fn ids(value: &DType) -> Vec<IRI> {
+  match value {
+    DType::String(iri) => vec![compact(iri)],
+    DType::Object(_) => {
+      value["@id"].as_str().map(compact).into_iter().collect()
+    }
+    DType::Array(values) => values.iter().flat_map(ids).collect(),
+    _ => vec![],
+  }
+}
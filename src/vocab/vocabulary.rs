@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-// TODO(victor): Use attribute macros to automate this task.
 /// `Vocabulary` is the base trait for all namespace IRIs' that implements a prefix & suffix.
 ///
+/// Rather than implementing it by hand as below, prefer the
+/// [`#[sage_vocab]`](crate::vocab::sage_vocab) attribute.
+///
 /// # Example
 ///
 /// ```rust
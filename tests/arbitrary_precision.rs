@@ -0,0 +1,51 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `serde_json::Value`s passing through sage's serializers when
+//! "arbitrary_precision" (and so serde_json's own) is enabled.
+
+#![cfg(feature = "arbitrary_precision")]
+
+use sage::{json, DType};
+
+#[test]
+fn to_dtype_keeps_serde_json_numbers() {
+  let value = serde_json::json!({ "a": 5, "big": 12345678901234567890123u128 });
+
+  let x = sage::to_dtype(&value).unwrap();
+  assert_eq!(
+    x,
+    sage::json!({ "a": 5, "big": 12345678901234567890123u128 })
+  );
+  assert!(x["a"].is_number());
+  assert_eq!(
+    json::to_string(&x["big"]).unwrap(),
+    "12345678901234567890123"
+  );
+}
+
+#[test]
+fn to_string_keeps_serde_json_numbers() {
+  let value = serde_json::json!({ "a": [5, 1.5, -2] });
+
+  assert_eq!(json::to_string(&value).unwrap(), r#"{"a":[5,1.5,-2]}"#);
+}
+
+#[test]
+fn from_value_keeps_serde_json_numbers() {
+  let value = serde_json::json!({ "a": 5 });
+
+  let x: DType = serde_json::from_value(value).unwrap();
+  assert_eq!(x, sage::json!({ "a": 5 }));
+}
@@ -0,0 +1,108 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Living specification of `sage::prelude`.
+//!
+//! Only `sage::prelude::*` is imported here, so any symbol dropped from the
+//! prelude fails to compile.
+
+use sage::prelude::*;
+
+#[test]
+fn dtype_types() {
+  let mut map: Map<String, DType> = Map::new();
+  map.insert("n".to_string(), DType::Number(Number::from(7)));
+  map.insert(
+    "d".to_string(),
+    DType::DateTime("2021-05-04".parse().unwrap()),
+  );
+
+  let date: DateTime = "2021-05-04".parse().unwrap();
+  let obj = DType::Object(map);
+  assert_eq!(obj["n"], 7);
+  assert_eq!(obj["d"], DType::DateTime(date));
+
+  let _iri: IRI = IRI::from("schema:Thing");
+  let _uri: URI = URI::from("https://schema.org/Thing");
+}
+
+#[test]
+fn json_macro_and_conversions() {
+  let value = json!({ "name": "Sage", "tags": ["kg", "rdf"] });
+
+  let dtype: DType = to_dtype(vec![1, 2, 3]).unwrap();
+  assert_eq!(dtype, json!([1, 2, 3]));
+
+  let tags: Vec<String> = from_dtype(value["tags"].clone()).unwrap();
+  assert_eq!(tags, vec!["kg", "rdf"]);
+
+  let text = json::to_string(&value).unwrap();
+  let parsed: DType = json::from_str(&text).unwrap();
+  assert_eq!(parsed, value);
+}
+
+#[test]
+fn vocabularies() {
+  let ns = Namespace::try_new("schema:", "https://schema.org/").unwrap();
+  let mut store = NamespaceStore::new();
+  store.add(&ns);
+  assert_eq!(
+    store.resolve_iri("schema:Movie"),
+    "https://schema.org/Movie"
+  );
+
+  assert_eq!(SchemaVocab::prefix(), "schema:");
+  assert_eq!(RdfVocab::prefix(), "rdf:");
+  assert_eq!(RdfsVocab::prefix(), "rdfs:");
+
+  let mut types = TypeHierarchy::new();
+  types.add_subclass("schema:Movie", "schema:CreativeWork");
+  assert!(types.is_subclass_of("schema:Movie", "schema:CreativeWork"));
+}
+
+#[test]
+fn graph_types() {
+  let node = Node::Literal(json!("Avatar"));
+  let predicate = Predicate::Uri(Namespace::default());
+  let connection = Connection::Forward;
+  let entity = Entity::schema("schema:Movie", "Avatar");
+  let triple = Triple::new(
+    entity.id(),
+    Predicate::Literal("sequel".to_string()),
+    Entity::literal("Avatar 2").id(),
+    connection,
+  );
+
+  assert!(node.is_literal());
+  assert!(predicate.is_uri());
+  assert!(connection.is_forward());
+  assert_eq!(triple.clone(), triple);
+
+  let mut store = NodeStore::new();
+  assert!(store.is_empty());
+  let id: NodeId = store.insert(entity);
+  assert_eq!(store.get(&id).unwrap().label(), Some("Avatar"));
+
+  let mut sk = Skolemizer::new("https://example.com");
+  assert!(sk.skolemize(&Node::Blank(None)).is_http());
+}
+
+#[test]
+fn errors() {
+  let err: Error = json::from_str::<DType>("{").unwrap_err();
+  assert_eq!(err.classify(), Category::Eof);
+
+  let result: SageResult<DType> = Err(err);
+  assert!(result.is_err());
+}
@@ -0,0 +1,97 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Golden-file tests for `DType::pretty_print`. The expected output lives in
+//! `tests/golden/` and assumes the default, sorted `Map` key order.
+
+use sage::{dtype::PrettyPrintConfig, json, DType};
+
+fn movie() -> DType {
+  json!({
+    "name": "Avatar",
+    "year": 2009,
+    "rating": 7.9,
+    "released": true,
+    "sequel": null,
+    "genres": ["Action", "Adventure", "Fantasy"],
+    "director": { "name": "James Cameron", "born": 1954 },
+    "cast": [
+      { "name": "Sam Worthington", "role": "Jake Sully" },
+      { "name": "Zoe Saldana", "role": "Neytiri" }
+    ],
+    "tags": [],
+    "awards": {},
+    "plot": "A paraplegic Marine dispatched to the moon Pandora on a unique mission becomes torn between following his orders and protecting the world he feels is his home."
+  })
+}
+
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
+fn check(config: PrettyPrintConfig, golden: &str) {
+  let pretty = movie().pretty_print(config);
+  assert_eq!(pretty, golden.trim_end());
+}
+
+#[test]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
+fn default_config() {
+  check(
+    PrettyPrintConfig::default(),
+    include_str!("golden/movie_default.json"),
+  );
+}
+
+#[test]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
+fn wide_indent() {
+  let config = PrettyPrintConfig {
+    indent: 4,
+    max_width: 40,
+    ..PrettyPrintConfig::default()
+  };
+  check(config, include_str!("golden/movie_indent4.json"));
+}
+
+#[test]
+#[cfg(not(any(feature = "preserve_order", feature = "fast_map")))]
+fn truncated_strings() {
+  let config = PrettyPrintConfig {
+    truncate_strings: Some(16),
+    ..PrettyPrintConfig::default()
+  };
+  check(config, include_str!("golden/movie_truncated.json"));
+}
+
+#[test]
+fn round_trips() {
+  for max_width in [0, 40, 80, 1000] {
+    let config = PrettyPrintConfig {
+      max_width,
+      ..PrettyPrintConfig::default()
+    };
+    let pretty = movie().pretty_print(config);
+    assert_eq!(json::from_str::<DType>(&pretty).unwrap(), movie());
+  }
+}
+
+#[test]
+#[cfg(feature = "termcolor")]
+fn colored() {
+  let config = PrettyPrintConfig {
+    color: true,
+    ..PrettyPrintConfig::default()
+  };
+  let colored = json!({ "name": "Avatar" }).pretty_print(config);
+  assert!(colored.contains("\u{1b}["));
+  assert!(colored.contains("Avatar"));
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Victor I. Afolabi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Queries against the bundled schema.org snapshot.
+
+#![cfg(feature = "schemaorg")]
+
+use sage::vocab::SchemaOrg;
+
+#[test]
+fn well_known_classes() {
+  let schema = SchemaOrg::get();
+
+  for class in [
+    "schema:Thing",
+    "schema:Person",
+    "schema:Organization",
+    "schema:Place",
+    "schema:Event",
+    "schema:CreativeWork",
+    "schema:Movie",
+    "schema:Book",
+    "schema:City",
+    "schema:Restaurant",
+    "schema:PostalAddress",
+    "schema:Date",
+  ] {
+    assert!(schema.class(class).is_some(), "missing {}", class);
+  }
+
+  let movie = schema.class("https://schema.org/Movie").unwrap();
+  assert_eq!(movie.label, "Movie");
+  assert_eq!(movie.sub_class_of, ["schema:CreativeWork"]);
+}
+
+#[test]
+fn subclasses() {
+  let schema = SchemaOrg::get();
+
+  assert!(schema.is_subclass_of("schema:Movie", "schema:Thing"));
+  assert!(schema.is_subclass_of("schema:NewsArticle", "schema:CreativeWork"));
+  assert!(schema.is_subclass_of("schema:Restaurant", "schema:Place"));
+  assert!(schema.is_subclass_of("schema:Restaurant", "schema:Organization"));
+  assert!(schema.is_subclass_of("schema:Integer", "schema:Number"));
+  assert!(!schema.is_subclass_of("schema:Person", "schema:Organization"));
+  assert!(!schema.is_subclass_of("schema:Thing", "schema:Movie"));
+}
+
+#[test]
+fn well_known_properties() {
+  let schema = SchemaOrg::get();
+
+  let name = schema.property("schema:name").unwrap();
+  assert_eq!(name.domain, ["schema:Thing"]);
+  assert_eq!(name.range, ["schema:Text"]);
+
+  let birth_date = schema.property("http://schema.org/birthDate").unwrap();
+  assert_eq!(birth_date.domain, ["schema:Person"]);
+  assert_eq!(birth_date.range, ["schema:Date"]);
+
+  let director = schema.property("schema:director").unwrap();
+  assert!(director.domain.iter().any(|d| d == "schema:Movie"));
+  assert_eq!(director.range, ["schema:Person"]);
+
+  assert!(schema.property("schema:author").is_some());
+  assert!(schema.property("schema:Movie").is_none());
+  assert!(schema.property("schema:noSuchProperty").is_none());
+}
+
+#[test]
+fn inverses() {
+  let schema = SchemaOrg::get();
+
+  assert_eq!(schema.inverse_of("schema:alumniOf"), Some("schema:alumni"));
+  assert_eq!(schema.inverse_of("schema:alumni"), Some("schema:alumniOf"));
+  assert_eq!(schema.inverse_of("schema:hasPart"), Some("schema:isPartOf"));
+  assert_eq!(
+    schema.inverse_of("schema:subOrganization"),
+    Some("schema:parentOrganization")
+  );
+  assert_eq!(schema.inverse_of("schema:spouse"), Some("schema:spouse"));
+  assert!(schema.property("schema:sibling").unwrap().symmetric);
+  assert_eq!(schema.inverse_of("schema:name"), None);
+}